@@ -1,17 +1,127 @@
 
 extern crate autotools;
+extern crate pkg_config;
+#[cfg(feature = "bindgen")]
+extern crate bindgen;
 
-use std::path::Path;
+use std::env;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 pub fn main() {
+    println!("cargo:rerun-if-env-changed=ARMASD_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=ARMASD_STATIC");
+    println!("cargo:rerun-if-env-changed=ARMASD_INCLUDE_DIR");
 
+    let mut libs = vec!["armasd"];
+    if has_feature("float32") {
+        libs.push("armass");
+    }
+
+    let includes = find_and_link(&libs);
+
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&includes);
+    #[cfg(not(feature = "bindgen"))]
+    let _ = includes;
+}
+
+/// Link armas libraries and return the include directories of the used library.
+fn find_and_link(libs: &[&str]) -> Vec<PathBuf> {
+    if !has_feature("vendored") {
+        if let Some(dir) = env::var_os("ARMASD_LIB_DIR") {
+            let dir = PathBuf::from(dir);
+            link_from_dir(&dir, libs);
+            return match env::var_os("ARMASD_INCLUDE_DIR") {
+                Some(inc) => vec![PathBuf::from(inc)],
+                None => dir.parent().map(|p| vec![p.join("include")]).unwrap_or_default(),
+            };
+        }
+        if let Some(includes) = link_from_pkg_config(libs) {
+            return includes;
+        }
+    }
+    vec![build_vendored().join("include")]
+}
+
+/// Link libraries found in directory given with ARMASD_LIB_DIR.
+fn link_from_dir(dir: &Path, libs: &[&str]) {
+    if !dir.is_dir() {
+        panic!("ARMASD_LIB_DIR={} is not a directory", dir.display());
+    }
+    let kind = if link_static() { "static" } else { "dylib" };
+    for lib in libs {
+        if !has_library(dir, lib) {
+            panic!("ARMASD_LIB_DIR={}: library {} not found", dir.display(), lib);
+        }
+        println!("cargo:rustc-link-lib={}={}", kind, lib);
+    }
+    println!("cargo:rustc-link-search=native={}", dir.display());
+    warn_build_features();
+}
+
+/// Look up installed libraries with pkg-config. Returns include directories or None
+/// if any of the libraries is not found.
+fn link_from_pkg_config(libs: &[&str]) -> Option<Vec<PathBuf>> {
+    // Probe all first so that nothing is emitted to cargo on partial success.
+    for lib in libs {
+        if pkg_config::Config::new().cargo_metadata(false).probe(lib).is_err() {
+            return None;
+        }
+    }
+    let mut includes = Vec::new();
+    for lib in libs {
+        match pkg_config::Config::new().statik(link_static()).probe(lib) {
+            Ok(found) => includes.extend(found.include_paths),
+            Err(e) => panic!("pkg-config failed for {}: {}", lib, e),
+        }
+    }
+    warn_build_features();
+    Some(includes)
+}
+
+/// Test if library file exists in directory.
+fn has_library(dir: &Path, lib: &str) -> bool {
+    let names = if link_static() {
+        vec![format!("lib{}.a", lib)]
+    } else {
+        vec![format!("lib{}.so", lib), format!("lib{}.dylib", lib), format!("lib{}.a", lib)]
+    };
+    names.iter().any(|name| dir.join(name).exists())
+}
+
+/// Link statically if ARMASD_STATIC is set to anything else than 0.
+fn link_static() -> bool {
+    match env::var("ARMASD_STATIC") {
+        Ok(v) => v != "0",
+        Err(_) => false,
+    }
+}
+
+/// Library build options cannot be applied to an installed library.
+fn warn_build_features() {
+    for option in &["ext-precision", "accelerators", "compat", "native-cpu"] {
+        if has_feature(option) {
+            println!("cargo:warning=feature {} has no effect on installed armas library", option);
+        }
+    }
+}
+
+/// Build armas from the bundled submodule. Returns the installation directory of
+/// the double precision library.
+fn build_vendored() -> PathBuf {
     if !Path::new("armas/.git").exists() {
         let _ = Command::new("git")
             .args(&["submodule", "update", "--init", "armas"])
             .status();
     }
 
+    if !Path::new("armas/configure.ac").exists() {
+        panic!("armas library not found. Set ARMASD_LIB_DIR, install armas with its \
+                pkg-config files or check out the bundled sources with \
+                'git submodule update --init'.");
+    }
+
     if !Path::new("armas/configure").exists() {
         let _ = Command::new("sh")
             .current_dir("./armas")
@@ -19,19 +129,92 @@ pub fn main() {
             .status();
     }
 
+    let dst = build_armas("float64", true);
+    println!("cargo:rustc-link-search=native={}/lib", dst.display());
+    println!("cargo:rustc-link-lib=static=armasd");
+
+    if has_feature("float32") {
+        // Single precision library keeps type names in symbols (armas_s_*) to
+        // avoid clashes with the double precision library.
+        let dst = build_armas("float32", false);
+        println!("cargo:rustc-link-search=native={}/lib", dst.display());
+        println!("cargo:rustc-link-lib=static=armass");
+    }
+    if has_feature("accelerators") {
+        println!("cargo:rustc-link-lib=pthread");
+    }
+    dst
+}
+
+/// Generate bindings from the armas headers into OUT_DIR/bindings.rs. The result is
+/// used to verify the hand written bindings in lib.rs.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(includes: &[PathBuf]) {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    // Typedefs pin the names of the types the bindings refer to.
+    let wrapper = out_dir.join("armasd_wrapper.h");
+    let mut header = String::from("\
+        #include <armas/dmatrix.h>\n\
+        #include <armas/dlinalg.h>\n\
+        typedef armas_dense_t armasd_rs_dense;\n\
+        typedef armas_pivot_t armasd_rs_pivot;\n\
+        typedef armas_conf_t armasd_rs_conf;\n\
+        typedef armas_eigen_parameter_t armasd_rs_eigen_parameter;\n");
+    if has_feature("float32") {
+        // Single precision declarations with type names (armas_s_*).
+        header.push_str("\
+            #include <armas/smatrix.h>\n\
+            #include <armas/slinalg.h>\n\
+            typedef armas_s_dense_t armasd_rs_sdense;\n\
+            typedef armas_s_eigen_parameter_t armasd_rs_seigen_parameter;\n");
+    }
+    std::fs::write(&wrapper, header)
+        .expect("failed to write bindgen wrapper header");
+
+    let mut builder = bindgen::Builder::default()
+        .header(wrapper.to_string_lossy())
+        .allowlist_function("armas_.*")
+        .allowlist_type("armas.*")
+        .allowlist_var("ARMAS_.*");
+    for dir in includes {
+        builder = builder.clang_arg(format!("-I{}", dir.display()));
+    }
+    builder.generate()
+        .expect("failed to generate armas bindings")
+        .write_to_file(out_dir.join("bindings.rs"))
+        .expect("failed to write armas bindings");
+}
+
+/// Configure, build and install armas for the requested element type. Returns
+/// the installation directory.
+fn build_armas(precision: &str, notypenames: bool) -> PathBuf {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
     let mut config = autotools::Config::new("armas");
 
-    config.enable("float64", None)
-        .enable("notypenames", None)
-        .disable("ext-precision", None)
-        .disable("accelerators", None)
-        .disable("compat", None)
+    config.out_dir(out_dir.join(precision))
+        .enable(precision, None)
         .disable("sparse", None)
         .cflag("-O3");
+    if notypenames {
+        config.enable("notypenames", None);
+    }
+    for option in &["ext-precision", "accelerators", "compat"] {
+        if has_feature(option) {
+            config.enable(option, None);
+        } else {
+            config.disable(option, None);
+        }
+    }
+    if has_feature("native-cpu") {
+        config.cflag("-march=native");
+    }
 
     config.make_target("all").build();
-    let dst = config.make_target("install").build();
+    config.make_target("install").build()
+}
 
-    println!("cargo:rustc-link-search=native={}/lib", dst.display());
-    println!("cargo:rustc-link-lib=static=armasd");
+/// Test if cargo feature is enabled.
+fn has_feature(name: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+    env::var_os(var).is_some()
 }
@@ -0,0 +1,373 @@
+//! Bindings to the single precision `armass` library.
+//!
+//! The single precision library is built with type names in its symbols
+//! (`armas_s_mult`, ...) so that it can be linked next to the double precision
+//! library. Functions are exported here with the same names as their double
+//! precision counterparts in the crate root. Functions that do not depend on
+//! the element type (pivots, configuration) are shared with the crate root.
+
+use ::std::os::raw::{c_int, c_void, c_float};
+
+use super::{armas_x_dense, armas_x_eigen_parameter, armas_conf, armas_pivot};
+
+pub type armas_dense = armas_x_dense<f32>;
+
+pub type armas_eigen_parameter = armas_x_eigen_parameter<f32>;
+
+pub type armas_generator = unsafe extern "C" fn() -> f32;
+pub type armas_valuefunc = unsafe extern "C" fn(r: c_int, c: c_int) -> f32;
+pub type armas_operator = unsafe extern "C" fn(x: f32) -> f32;
+pub type armas_operator2 = unsafe extern "C" fn(x: f32, y: *mut c_void) -> f32;
+pub type armas_iterator = unsafe extern "C" fn(x: f32, p: *mut c_void) -> c_int;
+
+#[link(name = "armass")]
+extern "C" {
+    #[link_name = "armas_s_init"]
+    pub fn armas_init(m: *mut armas_dense, r: c_int, c: c_int) -> *mut armas_dense;
+
+    #[link_name = "armas_s_make"]
+    pub fn armas_make(m: *mut armas_dense, r: c_int, c: c_int, s: c_int, buf: *mut f32) -> *mut armas_dense;
+
+    #[link_name = "armas_s_alloc"]
+    pub fn armas_alloc(r: c_int, c: c_int) -> *mut armas_dense;
+
+    #[link_name = "armas_s_release"]
+    pub fn armas_release(m: *mut armas_dense);
+
+    #[link_name = "armas_s_free"]
+    pub fn armas_free(m: *mut armas_dense);
+
+    #[link_name = "armas_s_set_unsafe"]
+    pub fn armas_set_unsafe(m: *mut armas_dense, r: c_int, c: c_int, v: c_float);
+
+    #[link_name = "armas_s_set_at_unsafe"]
+    pub fn armas_set_at_unsafe(m: *mut armas_dense, k: c_int, v: c_float);
+
+    #[link_name = "armas_s_get_unsafe"]
+    pub fn armas_get_unsafe(m: *const armas_dense, r: c_int, c: c_int) ->  c_float;
+
+    #[link_name = "armas_s_get_at_unsafe"]
+    pub fn armas_get_at_unsafe(m: *const armas_dense, k: c_int) ->  c_float;
+
+    #[link_name = "armas_s_row_unsafe"]
+    pub fn armas_row_unsafe(A: *mut armas_dense, B: *const armas_dense, r: c_int) ->  *mut armas_dense;
+
+    #[link_name = "armas_s_column_unsafe"]
+    pub fn armas_column_unsafe(A: *mut armas_dense, B: *const armas_dense, c: c_int) ->  *mut armas_dense;
+
+    #[link_name = "armas_s_diag_unsafe"]
+    pub fn armas_diag_unsafe(A: *mut armas_dense, B: *const armas_dense, k: c_int) ->  *mut armas_dense;
+
+    #[link_name = "armas_s_submatrix_unsafe"]
+    pub fn armas_submatrix_unsafe(
+        A: *mut armas_dense, B: *const armas_dense, r: c_int,
+        c: c_int, nr: c_int, nc: c_int) ->  *mut armas_dense;
+
+    #[link_name = "armas_s_mcopy"]
+    pub fn armas_mcopy(A: *mut armas_dense, B: *const armas_dense, flags: i32);
+
+    #[link_name = "armas_s_set_all"]
+    pub fn armas_set_all(m: *mut armas_dense, func: armas_generator, flags: c_int) -> c_int;
+
+    #[link_name = "armas_s_set_values"]
+    pub fn armas_set_values(m: *mut armas_dense, func: armas_valuefunc, flags: c_int) -> c_int;
+
+    #[link_name = "armas_s_make_trm"]
+    pub fn armas_make_trm(m: *mut armas_dense, flags: c_int);
+
+    #[link_name = "armas_s_madd"]
+    pub fn armas_madd(d: *mut armas_dense, alpha: f32, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mscale"]
+    pub fn armas_mscale(d: *mut armas_dense, alpha: f32, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_scale"]
+    pub fn armas_scale(d: *mut armas_dense, alpha: f32, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mplus"]
+    pub fn armas_mplus(
+        alpha: f32, A: *mut armas_dense, beta: f32, B: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_apply"]
+    pub fn armas_apply(A: *mut armas_dense, func: armas_operator, flags: c_int) -> c_int;
+
+    #[link_name = "armas_s_apply2"]
+    pub fn armas_apply2(A: *mut armas_dense, func: armas_operator2, arg: *mut c_void, flags: c_int) -> c_int;
+
+    #[link_name = "armas_s_iterate"]
+    pub fn armas_iterate(A: *const armas_dense, func: armas_iterator, p: *mut c_void, flags: c_int) -> c_int;
+
+    #[link_name = "armas_s_normal"]
+    pub fn armas_normal() -> f32;
+    #[link_name = "armas_s_uniform"]
+    pub fn armas_uniform() -> f32;
+
+    #[link_name = "armas_s_mnorm"]
+    pub fn armas_mnorm(A: *const armas_dense, norm: c_int, cf: *mut armas_conf) -> f32;
+
+    // Blas 1
+    #[link_name = "armas_s_iamax"]
+    pub fn armas_iamax(X: *const armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_amax"]
+    pub fn armas_amax(X: *const armas_dense, cf: *mut armas_conf) -> f32;
+
+    #[link_name = "armas_s_asum"]
+    pub fn armas_asum(X: *const armas_dense, cf: *mut armas_conf) -> f32;
+
+    #[link_name = "armas_s_nrm2"]
+    pub fn armas_nrm2(X: *const armas_dense, cf: *mut armas_conf) -> f32;
+
+    #[link_name = "armas_s_dot"]
+    pub fn armas_dot(X: *const armas_dense, Y: *const armas_dense, cf: *mut armas_conf) -> f32;
+
+    #[link_name = "armas_s_adot"]
+    pub fn armas_adot(result: *mut f32, alpha: f32, X: *const armas_dense, Y: *const armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_axpy"]
+    pub fn armas_axpy(Y: *mut armas_dense, alpha: f32, X: *const armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_axpby"]
+    pub fn armas_axpby(beta: f32, Y: *mut armas_dense, alpha: f32, X: *const armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_copy"]
+    pub fn armas_copy(Y: *mut armas_dense, X: *const armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_swap"]
+    pub fn armas_swap(Y: *mut armas_dense, X: *mut armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_sum"]
+    pub fn armas_sum(X: *const armas_dense, cf: *mut armas_conf) -> f32;
+
+    // BLAS2
+    #[link_name = "armas_s_mvmult"]
+    pub fn armas_mvmult(
+        beta: f32, Y: *mut armas_dense, alpha: f32, A: *const armas_dense, X: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mvupdate"]
+    pub fn armas_mvupdate(
+        beta: f32, A: *mut armas_dense, alpha: f32, X: *const armas_dense, Y: *const armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mvmult_sym"]
+    pub fn armas_mvmult_sym(
+        beta: f32, Y: *mut armas_dense, alpha: f32, A: *const armas_dense, X: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mvupdate2_sym"]
+    pub fn armas_mvupdate2_sym(
+        beta: f32, A: *mut armas_dense, alpha: f32, X: *const armas_dense, Y: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mvupdate_sym"]
+    pub fn armas_mvupdate_sym(
+        beta: f32, A: *mut armas_dense, alpha: f32, X: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mvupdate_trm"]
+    pub fn armas_mvupdate_trm(
+        beta: f32, A: *mut armas_dense, alpha: f32, X: *const armas_dense, Y: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mvmult_trm"]
+    pub fn armas_mvmult_trm(
+        X: *mut armas_dense, alpha: f32, A: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mvsolve_trm"]
+    pub fn armas_mvsolve_trm(
+        X: *mut armas_dense, alpha: f32, A: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    // Blas3
+    #[link_name = "armas_s_mult"]
+    pub fn armas_mult(
+        beta: f32, C: *mut armas_dense, alpha: f32, A: *const armas_dense, B: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mult_sym"]
+    pub fn armas_mult_sym(
+        beta: f32, C: *mut armas_dense, alpha: f32, A: *const armas_dense, B: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mult_trm"]
+    pub fn armas_mult_trm(
+        B: *mut armas_dense, alpha: f32, A: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_solve_trm"]
+    pub fn armas_solve_trm(
+        B: *mut armas_dense, alpha: f32, A: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_update_trm"]
+    pub fn armas_update_trm(
+        beta: f32, C: *mut armas_dense, alpha: f32, A: *const armas_dense, B: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_update_sym"]
+    pub fn armas_update_sym(
+        beta: f32, C: *mut armas_dense, alpha: f32, A: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_update2_sym"]
+    pub fn armas_update2_sym(
+        beta: f32, C: *mut armas_dense, alpha: f32, A: *const armas_dense, B: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_mult_diag"]
+    pub fn armas_mult_diag(
+        B: *mut armas_dense, alpha: f32, A: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_solve_diag"]
+    pub fn armas_solve_diag(
+        B: *mut armas_dense, alpha: f32, A: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_qrbuild"]
+    pub fn armas_qrbuild(
+        A: *mut armas_dense, tau: *const armas_dense, K: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_qrfactor"]
+    pub fn armas_qrfactor(
+        A: *mut armas_dense, tau: *mut armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_qrmult"]
+    pub fn armas_qrmult(
+        C: *mut armas_dense, A: *const armas_dense, tau: *const armas_dense, flags: c_int,cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_qrsolve"]
+    pub fn armas_qrsolve(
+        B: *mut armas_dense, A: *const armas_dense, tau: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_lqbuild"]
+    pub fn armas_lqbuild(
+        A: *mut armas_dense, tau: *const armas_dense, K: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_lqfactor"]
+    pub fn armas_lqfactor(
+        A: *mut armas_dense, tau: *mut armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_lqmult"]
+    pub fn armas_lqmult(
+        C: *mut armas_dense, A: *const armas_dense, tau: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_lqsolve"]
+    pub fn armas_lqsolve(
+        B: *mut armas_dense, A: *const armas_dense, tau: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_ldlfactor"]
+    pub fn armas_ldlfactor(
+        A: *mut armas_dense, P: *mut armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_ldlsolve"]
+    pub fn armas_ldlsolve(
+        B: *mut armas_dense, A: *const armas_dense, P: *const armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_bkfactor"]
+    pub fn armas_bkfactor(
+        A: *mut armas_dense, P: *mut armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_bksolve"]
+    pub fn armas_bksolve(
+        B: *mut armas_dense, A: *const armas_dense, P: *const armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_lufactor"]
+    pub fn armas_lufactor(
+        A: *mut armas_dense, P: *mut armas_pivot, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_lusolve"]
+    pub fn armas_lusolve(
+        B: *mut armas_dense, A: *mut armas_dense, P: *mut armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_hessreduce"]
+    pub fn armas_hessreduce(
+        A: *mut armas_dense, tau: *mut armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_hessmult"]
+    pub fn armas_hessmult(
+        B: *mut armas_dense, A: *const armas_dense, tau: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_cholesky"]
+    pub fn armas_cholesky(
+        A: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_cholfactor"]
+    pub fn armas_cholfactor(
+        A: *mut armas_dense, P: *mut armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_cholsolve"]
+    pub fn armas_cholsolve(
+        B: *mut armas_dense, A: *const armas_dense, P: *const armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_cholupdate"]
+    pub fn armas_cholupdate(
+        A: *mut armas_dense, X: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_bdreduce"]
+    pub fn armas_bdreduce(
+        A: *mut armas_dense, tauq: *mut armas_dense, taup: *mut armas_dense, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_bdbuild"]
+    pub fn armas_bdbuild(
+        A: *mut armas_dense, tau: *const armas_dense, K: c_int, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_bdmult"]
+    pub fn armas_bdmult(
+        B: *mut armas_dense, A: *const armas_dense, tau: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_trdreduce"]
+    pub fn armas_trdreduce(
+        A: *mut armas_dense, tau: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_trdbuild"]
+    pub fn armas_trdbuild(
+        A: *mut armas_dense, tau: *const armas_dense, K: c_int, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_trdmult"]
+    pub fn armas_trdmult(
+        B: *mut armas_dense, A: *const armas_dense, tau: *const armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_trdeigen"]
+    pub fn armas_trdeigen(
+        D: *mut armas_dense, E: *mut armas_dense, V: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_trdbisect"]
+    pub fn armas_trdbisect(
+        Y: *mut armas_dense, D: *mut armas_dense, E: *mut armas_dense, params: *const armas_eigen_parameter, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_gvcompute"]
+    pub fn armas_gvcompute(c: *mut f32, s: *mut f32, r: *mut f32, a: f32, b: f32);
+
+    #[link_name = "armas_s_gvrotate"]
+    pub fn armas_gvrotate(v0: *mut f32, v1: *mut f32, c: f32, s: f32, y0: f32, y1: f32);
+
+    #[link_name = "armas_s_gvleft"]
+    pub fn armas_gvleft(
+        A: *mut armas_dense, c: f32, s: f32, r1: c_int, r2: c_int, col: c_int, ncol: c_int);
+
+    #[link_name = "armas_s_gvright"]
+    pub fn armas_gvright(
+        A: *mut armas_dense, c: f32, s: f32, r1: c_int, r2: c_int, col: c_int, ncol: c_int);
+
+    #[link_name = "armas_s_gvupdate"]
+    pub fn armas_gvupdate(
+        A: *mut armas_dense, start: c_int, C: *mut armas_dense, S: *mut armas_dense, nrot: c_int, flags: c_int) -> c_int;
+
+    #[link_name = "armas_s_gvrot_vec"]
+    pub fn armas_gvrot_vec(
+        X: *mut armas_dense, Y: *mut armas_dense, c: f32, s: f32) -> c_int;
+
+    #[link_name = "armas_s_bdsvd"]
+    pub fn armas_bdsvd(
+        D: *mut armas_dense, E: *mut armas_dense, U: *mut armas_dense, V: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_svd"]
+    pub fn armas_svd(
+        S: *mut armas_dense, U: *mut armas_dense, V: *mut armas_dense, A: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_eigen_sym"]
+    pub fn armas_eigen_sym(
+        D: *mut armas_dense, A: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_eigen_sym_selected"]
+    pub fn armas_eigen_sym_selected(
+        D: *mut armas_dense, A: *mut armas_dense, params: *const armas_eigen_parameter, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_luinverse"]
+    pub fn armas_luinverse(
+        A: *mut armas_dense, P: *const armas_pivot, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_cholinverse"]
+    pub fn armas_cholinverse(
+        A: *mut armas_dense, flags: c_int, cf: *mut armas_conf) -> c_int;
+
+    #[link_name = "armas_s_ldlinverse"]
+    pub fn armas_ldlinverse(
+        A: *mut armas_dense, P: *const armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
+}
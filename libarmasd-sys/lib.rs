@@ -1,3 +1,19 @@
+//! Raw bindings to the armas linear algebra library.
+//!
+//! The library is located in the following order:
+//!
+//! 1. `ARMASD_LIB_DIR` environment variable naming the directory of `libarmasd`
+//!    (and `libarmass` with the `float32` feature). Set `ARMASD_STATIC=1` to link
+//!    static libraries.
+//! 2. `pkg-config` modules `armasd` (and `armass`).
+//! 3. Bundled sources in the `armas` submodule, built with autotools.
+//!
+//! The `vendored` feature skips the first two steps.
+//!
+//! With the `bindgen` feature bindings are generated from the armas headers of the
+//! located library (`ARMASD_INCLUDE_DIR` overrides the include directory) and
+//! `cargo test --features bindgen` checks that the hand written declarations here
+//! match them.
 
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
@@ -5,6 +21,9 @@
 
 use ::std::os::raw::{c_int, c_uint, c_void, c_double};
 
+#[cfg(feature = "float32")]
+pub mod float32;
+
 pub const ARMAS_NOTRANS: c_int = 0;
 pub const ARMAS_NONE: c_int = 0;
 pub const ARMAS_ALL: c_int = 0;
@@ -86,10 +105,16 @@ pub const ARMAS_NORM_TWO: c_uint = 2;
 pub const ARMAS_NORM_INF: c_uint = 3;
 pub const ARMAS_NORM_FRB: c_uint = 4;
 
+pub const ARMAS_AC_SERIAL: c_int = 0;
+pub const ARMAS_AC_THREADED: c_int = 1;
+
+pub const ARMAS_AC_WORKERS: c_int = 1;
+
+/// Dense matrix descriptor, generic over the element type of the library build.
 #[repr(C)]
 #[derive(Debug)]
-pub struct armas_dense {
-    pub elems: *mut f64,
+pub struct armas_x_dense<T> {
+    pub elems: *mut T,
     pub step: c_int,
     pub rows: c_int,
     pub cols: c_int,
@@ -97,6 +122,8 @@ pub struct armas_dense {
     __nbytes: c_int,
 }
 
+pub type armas_dense = armas_x_dense<f64>;
+
 #[repr(C)]
 #[derive(Debug)]
 pub struct armas_pivot {
@@ -123,14 +150,19 @@ pub struct armas_conf {
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct armas_eigen_parameter {
+pub struct armas_x_eigen_parameter<T> {
     pub ileft: c_int,
     pub iright: c_int,
-    pub left: f64,
-    pub right: f64,
-    pub tau: f64,
+    pub left: T,
+    pub right: T,
+    pub tau: T,
 }
 
+pub type armas_eigen_parameter = armas_x_eigen_parameter<f64>;
+
+/// Opaque accelerator handle.
+pub type armas_ac_handle_t = *mut c_void;
+
 pub type armas_generator = unsafe extern "C" fn() -> f64;
 pub type armas_valuefunc = unsafe extern "C" fn(r: c_int, c: c_int) -> f64;
 pub type armas_operator = unsafe extern "C" fn(x: f64) -> f64;
@@ -403,3 +435,562 @@ extern "C" {
         A: *mut armas_dense, P: *const armas_pivot, flags: c_int, cf: *mut armas_conf) -> c_int;
 
     }
+
+#[cfg(feature = "accelerators")]
+#[link(name = "armasd")]
+extern "C" {
+    pub fn armas_ac_init(ac: *mut armas_ac_handle_t, actype: c_int) -> c_int;
+
+    pub fn armas_ac_release(ac: armas_ac_handle_t);
+
+    pub fn armas_ac_default() -> armas_ac_handle_t;
+
+    pub fn armas_ac_set_default(ac: armas_ac_handle_t);
+
+    pub fn armas_ac_get(ac: armas_ac_handle_t, op: c_int, arg: *mut c_void) -> c_int;
+
+    pub fn armas_ac_set(ac: armas_ac_handle_t, op: c_int, arg: *mut c_void) -> c_int;
+}
+
+#[cfg(all(test, feature = "bindgen"))]
+mod layout {
+    use std::mem::{align_of, offset_of, size_of};
+
+    #[allow(dead_code, non_upper_case_globals, non_camel_case_types, non_snake_case, clashing_extern_declarations)]
+    #[allow(clippy::all)]
+    mod generated {
+        include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+    }
+
+    use ::std::os::raw::{c_int, c_void, c_double};
+    #[cfg(feature = "float32")]
+    use ::std::os::raw::c_float;
+    use super::{armas_pivot, armas_conf};
+
+    // Generated counterpart of a type in the hand written bindings.
+    trait Generated {
+        type Type;
+    }
+
+    macro_rules! generated_as {
+        ($($ours:ty => $theirs:ty),*) => {
+            $( impl Generated for $ours { type Type = $theirs; } )*
+        }
+    }
+
+    generated_as!(c_int => c_int, f64 => f64, f32 => f32, c_void => c_void,
+                  super::armas_dense => generated::armasd_rs_dense,
+                  super::armas_eigen_parameter => generated::armasd_rs_eigen_parameter,
+                  armas_pivot => generated::armasd_rs_pivot,
+                  armas_conf => generated::armasd_rs_conf);
+
+    #[cfg(feature = "float32")]
+    generated_as!(super::float32::armas_dense => generated::armasd_rs_sdense,
+                  super::float32::armas_eigen_parameter => generated::armasd_rs_seigen_parameter);
+
+    // Function pointer typedefs are nullable in generated bindings.
+    macro_rules! generated_callbacks {
+        ($($t:ty),*) => {
+            generated_as!($(unsafe extern "C" fn() -> $t => Option<unsafe extern "C" fn() -> $t>,
+                            unsafe extern "C" fn(c_int, c_int) -> $t => Option<unsafe extern "C" fn(c_int, c_int) -> $t>,
+                            unsafe extern "C" fn($t) -> $t => Option<unsafe extern "C" fn($t) -> $t>,
+                            unsafe extern "C" fn($t, *mut c_void) -> $t => Option<unsafe extern "C" fn($t, *mut c_void) -> $t>,
+                            unsafe extern "C" fn($t, *mut c_void) -> c_int => Option<unsafe extern "C" fn($t, *mut c_void) -> c_int>),*);
+        }
+    }
+
+    generated_callbacks!(f64, f32);
+
+    impl<T: Generated> Generated for *mut T {
+        type Type = *mut T::Type;
+    }
+
+    impl<T: Generated> Generated for *const T {
+        type Type = *const T::Type;
+    }
+
+    macro_rules! assert_same_layout {
+        ($ours:ty, $theirs:ty, $($field:ident),*) => {
+            assert_eq!(size_of::<$ours>(), size_of::<$theirs>(), "size of {}", stringify!($ours));
+            assert_eq!(align_of::<$ours>(), align_of::<$theirs>(), "alignment of {}", stringify!($ours));
+            $(
+                assert_eq!(offset_of!($ours, $field), offset_of!($theirs, $field),
+                           "offset of {}.{}", stringify!($ours), stringify!($field));
+            )*
+        }
+    }
+
+    macro_rules! assert_same_values {
+        ($($name:ident),*) => {
+            $(
+                assert_eq!(super::$name as i64, generated::$name as i64, "value of {}", stringify!($name));
+            )*
+        }
+    }
+
+    // Check that hand written function has the given signature and that the generated
+    // function has the same signature with generated types.
+    macro_rules! assert_same_signature {
+        ($ours:path, $theirs:path, ($($arg:ty),*) $(-> $ret:ty)?) => {
+            let _: unsafe extern "C" fn($($arg),*) $(-> $ret)? = $ours;
+            let _: unsafe extern "C" fn($(<$arg as Generated>::Type),*) $(-> <$ret as Generated>::Type)? = $theirs;
+        }
+    }
+
+    #[test]
+    fn test_signatures() {
+        use super::{armas_dense, armas_eigen_parameter, armas_generator, armas_valuefunc,
+                    armas_operator, armas_operator2, armas_iterator};
+
+        assert_same_signature!(super::armas_pivot_init, generated::armas_pivot_init,
+            (*mut armas_pivot, c_int) -> *mut armas_pivot);
+        assert_same_signature!(super::armas_pivot_release, generated::armas_pivot_release,
+            (*mut armas_pivot));
+        assert_same_signature!(super::armas_pivot_get_unsafe, generated::armas_pivot_get_unsafe,
+            (*mut armas_pivot, c_int) -> c_int);
+        assert_same_signature!(super::armas_pivot_set_unsafe, generated::armas_pivot_set_unsafe,
+            (*mut armas_pivot, c_int, c_int));
+        assert_same_signature!(super::armas_conf_default, generated::armas_conf_default,
+            () -> *mut armas_conf);
+        assert_same_signature!(super::armas_init, generated::armas_init,
+            (*mut armas_dense, c_int, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::armas_make, generated::armas_make,
+            (*mut armas_dense, c_int, c_int, c_int, *mut f64) -> *mut armas_dense);
+        assert_same_signature!(super::armas_alloc, generated::armas_alloc,
+            (c_int, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::armas_release, generated::armas_release, (*mut armas_dense));
+        assert_same_signature!(super::armas_free, generated::armas_free, (*mut armas_dense));
+        assert_same_signature!(super::armas_set_unsafe, generated::armas_set_unsafe,
+            (*mut armas_dense, c_int, c_int, c_double));
+        assert_same_signature!(super::armas_set_at_unsafe, generated::armas_set_at_unsafe,
+            (*mut armas_dense, c_int, c_double));
+        assert_same_signature!(super::armas_get_unsafe, generated::armas_get_unsafe,
+            (*const armas_dense, c_int, c_int) -> c_double);
+        assert_same_signature!(super::armas_get_at_unsafe, generated::armas_get_at_unsafe,
+            (*const armas_dense, c_int) -> c_double);
+        assert_same_signature!(super::armas_row_unsafe, generated::armas_row_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::armas_column_unsafe, generated::armas_column_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::armas_diag_unsafe, generated::armas_diag_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::armas_submatrix_unsafe, generated::armas_submatrix_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int, c_int, c_int, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::armas_mcopy, generated::armas_mcopy,
+            (*mut armas_dense, *const armas_dense, i32));
+        assert_same_signature!(super::armas_set_all, generated::armas_set_all,
+            (*mut armas_dense, armas_generator, c_int) -> c_int);
+        assert_same_signature!(super::armas_set_values, generated::armas_set_values,
+            (*mut armas_dense, armas_valuefunc, c_int) -> c_int);
+        assert_same_signature!(super::armas_make_trm, generated::armas_make_trm, (*mut armas_dense, c_int));
+        assert_same_signature!(super::armas_madd, generated::armas_madd,
+            (*mut armas_dense, f64, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mscale, generated::armas_mscale,
+            (*mut armas_dense, f64, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_scale, generated::armas_scale,
+            (*mut armas_dense, f64, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mplus, generated::armas_mplus,
+            (f64, *mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_apply, generated::armas_apply,
+            (*mut armas_dense, armas_operator, c_int) -> c_int);
+        assert_same_signature!(super::armas_apply2, generated::armas_apply2,
+            (*mut armas_dense, armas_operator2, *mut c_void, c_int) -> c_int);
+        assert_same_signature!(super::armas_iterate, generated::armas_iterate,
+            (*const armas_dense, armas_iterator, *mut c_void, c_int) -> c_int);
+        assert_same_signature!(super::armas_normal, generated::armas_normal, () -> f64);
+        assert_same_signature!(super::armas_uniform, generated::armas_uniform, () -> f64);
+        assert_same_signature!(super::armas_mnorm, generated::armas_mnorm,
+            (*const armas_dense, c_int, *mut armas_conf) -> f64);
+        assert_same_signature!(super::armas_iamax, generated::armas_iamax,
+            (*const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_amax, generated::armas_amax,
+            (*const armas_dense, *mut armas_conf) -> f64);
+        assert_same_signature!(super::armas_asum, generated::armas_asum,
+            (*const armas_dense, *mut armas_conf) -> f64);
+        assert_same_signature!(super::armas_nrm2, generated::armas_nrm2,
+            (*const armas_dense, *mut armas_conf) -> f64);
+        assert_same_signature!(super::armas_dot, generated::armas_dot,
+            (*const armas_dense, *const armas_dense, *mut armas_conf) -> f64);
+        assert_same_signature!(super::armas_adot, generated::armas_adot,
+            (*mut f64, f64, *const armas_dense, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_axpy, generated::armas_axpy,
+            (*mut armas_dense, f64, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_axpby, generated::armas_axpby,
+            (f64, *mut armas_dense, f64, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_copy, generated::armas_copy,
+            (*mut armas_dense, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_swap, generated::armas_swap,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_sum, generated::armas_sum,
+            (*const armas_dense, *mut armas_conf) -> f64);
+        assert_same_signature!(super::armas_mvmult, generated::armas_mvmult,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mvupdate, generated::armas_mvupdate,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mvmult_sym, generated::armas_mvmult_sym,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mvupdate2_sym, generated::armas_mvupdate2_sym,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mvupdate_sym, generated::armas_mvupdate_sym,
+            (f64, *mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mvupdate_trm, generated::armas_mvupdate_trm,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mvmult_trm, generated::armas_mvmult_trm,
+            (*mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mvsolve_trm, generated::armas_mvsolve_trm,
+            (*mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mult, generated::armas_mult,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mult_sym, generated::armas_mult_sym,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mult_trm, generated::armas_mult_trm,
+            (*mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_solve_trm, generated::armas_solve_trm,
+            (*mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_update_trm, generated::armas_update_trm,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_update_sym, generated::armas_update_sym,
+            (f64, *mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_update2_sym, generated::armas_update2_sym,
+            (f64, *mut armas_dense, f64, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_mult_diag, generated::armas_mult_diag,
+            (*mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_solve_diag, generated::armas_solve_diag,
+            (*mut armas_dense, f64, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_qrbuild, generated::armas_qrbuild,
+            (*mut armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_qrfactor, generated::armas_qrfactor,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_qrmult, generated::armas_qrmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_qrsolve, generated::armas_qrsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_lqbuild, generated::armas_lqbuild,
+            (*mut armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_lqfactor, generated::armas_lqfactor,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_lqmult, generated::armas_lqmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_lqsolve, generated::armas_lqsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_ldlfactor, generated::armas_ldlfactor,
+            (*mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_ldlsolve, generated::armas_ldlsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_bkfactor, generated::armas_bkfactor,
+            (*mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_bksolve, generated::armas_bksolve,
+            (*mut armas_dense, *const armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_lufactor, generated::armas_lufactor,
+            (*mut armas_dense, *mut armas_pivot, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_lusolve, generated::armas_lusolve,
+            (*mut armas_dense, *mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_hessreduce, generated::armas_hessreduce,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_hessmult, generated::armas_hessmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_cholesky, generated::armas_cholesky,
+            (*mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_cholfactor, generated::armas_cholfactor,
+            (*mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_cholsolve, generated::armas_cholsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_cholupdate, generated::armas_cholupdate,
+            (*mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_bdreduce, generated::armas_bdreduce,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_bdbuild, generated::armas_bdbuild,
+            (*mut armas_dense, *const armas_dense, c_int, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_bdmult, generated::armas_bdmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_trdreduce, generated::armas_trdreduce,
+            (*mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_trdbuild, generated::armas_trdbuild,
+            (*mut armas_dense, *const armas_dense, c_int, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_trdmult, generated::armas_trdmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_trdeigen, generated::armas_trdeigen,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_trdbisect, generated::armas_trdbisect,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *const armas_eigen_parameter, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_gvcompute, generated::armas_gvcompute,
+            (*mut f64, *mut f64, *mut f64, f64, f64));
+        assert_same_signature!(super::armas_gvrotate, generated::armas_gvrotate,
+            (*mut f64, *mut f64, f64, f64, f64, f64));
+        assert_same_signature!(super::armas_gvleft, generated::armas_gvleft,
+            (*mut armas_dense, f64, f64, c_int, c_int, c_int, c_int));
+        assert_same_signature!(super::armas_gvright, generated::armas_gvright,
+            (*mut armas_dense, f64, f64, c_int, c_int, c_int, c_int));
+        assert_same_signature!(super::armas_gvupdate, generated::armas_gvupdate,
+            (*mut armas_dense, c_int, *mut armas_dense, *mut armas_dense, c_int, c_int) -> c_int);
+        assert_same_signature!(super::armas_gvrot_vec, generated::armas_gvrot_vec,
+            (*mut armas_dense, *mut armas_dense, f64, f64) -> c_int);
+        assert_same_signature!(super::armas_bdsvd, generated::armas_bdsvd,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_svd, generated::armas_svd,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_eigen_sym, generated::armas_eigen_sym,
+            (*mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_eigen_sym_selected, generated::armas_eigen_sym_selected,
+            (*mut armas_dense, *mut armas_dense, *const armas_eigen_parameter, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_luinverse, generated::armas_luinverse,
+            (*mut armas_dense, *const armas_pivot, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_cholinverse, generated::armas_cholinverse,
+            (*mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::armas_ldlinverse, generated::armas_ldlinverse,
+            (*mut armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+    }
+
+    #[cfg(feature = "float32")]
+    #[test]
+    fn test_signatures_float32() {
+        use super::float32::{armas_dense, armas_eigen_parameter, armas_generator, armas_valuefunc,
+                             armas_operator, armas_operator2, armas_iterator};
+
+        assert_same_signature!(super::float32::armas_init, generated::armas_s_init,
+            (*mut armas_dense, c_int, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::float32::armas_make, generated::armas_s_make,
+            (*mut armas_dense, c_int, c_int, c_int, *mut f32) -> *mut armas_dense);
+        assert_same_signature!(super::float32::armas_alloc, generated::armas_s_alloc,
+            (c_int, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::float32::armas_release, generated::armas_s_release, (*mut armas_dense));
+        assert_same_signature!(super::float32::armas_free, generated::armas_s_free, (*mut armas_dense));
+        assert_same_signature!(super::float32::armas_set_unsafe, generated::armas_s_set_unsafe,
+            (*mut armas_dense, c_int, c_int, c_float));
+        assert_same_signature!(super::float32::armas_set_at_unsafe, generated::armas_s_set_at_unsafe,
+            (*mut armas_dense, c_int, c_float));
+        assert_same_signature!(super::float32::armas_get_unsafe, generated::armas_s_get_unsafe,
+            (*const armas_dense, c_int, c_int) -> c_float);
+        assert_same_signature!(super::float32::armas_get_at_unsafe, generated::armas_s_get_at_unsafe,
+            (*const armas_dense, c_int) -> c_float);
+        assert_same_signature!(super::float32::armas_row_unsafe, generated::armas_s_row_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::float32::armas_column_unsafe, generated::armas_s_column_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::float32::armas_diag_unsafe, generated::armas_s_diag_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::float32::armas_submatrix_unsafe, generated::armas_s_submatrix_unsafe,
+            (*mut armas_dense, *const armas_dense, c_int, c_int, c_int, c_int) -> *mut armas_dense);
+        assert_same_signature!(super::float32::armas_mcopy, generated::armas_s_mcopy,
+            (*mut armas_dense, *const armas_dense, i32));
+        assert_same_signature!(super::float32::armas_set_all, generated::armas_s_set_all,
+            (*mut armas_dense, armas_generator, c_int) -> c_int);
+        assert_same_signature!(super::float32::armas_set_values, generated::armas_s_set_values,
+            (*mut armas_dense, armas_valuefunc, c_int) -> c_int);
+        assert_same_signature!(super::float32::armas_make_trm, generated::armas_s_make_trm,
+            (*mut armas_dense, c_int));
+        assert_same_signature!(super::float32::armas_madd, generated::armas_s_madd,
+            (*mut armas_dense, f32, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mscale, generated::armas_s_mscale,
+            (*mut armas_dense, f32, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_scale, generated::armas_s_scale,
+            (*mut armas_dense, f32, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mplus, generated::armas_s_mplus,
+            (f32, *mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_apply, generated::armas_s_apply,
+            (*mut armas_dense, armas_operator, c_int) -> c_int);
+        assert_same_signature!(super::float32::armas_apply2, generated::armas_s_apply2,
+            (*mut armas_dense, armas_operator2, *mut c_void, c_int) -> c_int);
+        assert_same_signature!(super::float32::armas_iterate, generated::armas_s_iterate,
+            (*const armas_dense, armas_iterator, *mut c_void, c_int) -> c_int);
+        assert_same_signature!(super::float32::armas_normal, generated::armas_s_normal, () -> f32);
+        assert_same_signature!(super::float32::armas_uniform, generated::armas_s_uniform, () -> f32);
+        assert_same_signature!(super::float32::armas_mnorm, generated::armas_s_mnorm,
+            (*const armas_dense, c_int, *mut armas_conf) -> f32);
+        assert_same_signature!(super::float32::armas_iamax, generated::armas_s_iamax,
+            (*const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_amax, generated::armas_s_amax,
+            (*const armas_dense, *mut armas_conf) -> f32);
+        assert_same_signature!(super::float32::armas_asum, generated::armas_s_asum,
+            (*const armas_dense, *mut armas_conf) -> f32);
+        assert_same_signature!(super::float32::armas_nrm2, generated::armas_s_nrm2,
+            (*const armas_dense, *mut armas_conf) -> f32);
+        assert_same_signature!(super::float32::armas_dot, generated::armas_s_dot,
+            (*const armas_dense, *const armas_dense, *mut armas_conf) -> f32);
+        assert_same_signature!(super::float32::armas_adot, generated::armas_s_adot,
+            (*mut f32, f32, *const armas_dense, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_axpy, generated::armas_s_axpy,
+            (*mut armas_dense, f32, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_axpby, generated::armas_s_axpby,
+            (f32, *mut armas_dense, f32, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_copy, generated::armas_s_copy,
+            (*mut armas_dense, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_swap, generated::armas_s_swap,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_sum, generated::armas_s_sum,
+            (*const armas_dense, *mut armas_conf) -> f32);
+        assert_same_signature!(super::float32::armas_mvmult, generated::armas_s_mvmult,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mvupdate, generated::armas_s_mvupdate,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mvmult_sym, generated::armas_s_mvmult_sym,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mvupdate2_sym, generated::armas_s_mvupdate2_sym,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mvupdate_sym, generated::armas_s_mvupdate_sym,
+            (f32, *mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mvupdate_trm, generated::armas_s_mvupdate_trm,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mvmult_trm, generated::armas_s_mvmult_trm,
+            (*mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mvsolve_trm, generated::armas_s_mvsolve_trm,
+            (*mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mult, generated::armas_s_mult,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mult_sym, generated::armas_s_mult_sym,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mult_trm, generated::armas_s_mult_trm,
+            (*mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_solve_trm, generated::armas_s_solve_trm,
+            (*mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_update_trm, generated::armas_s_update_trm,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_update_sym, generated::armas_s_update_sym,
+            (f32, *mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_update2_sym, generated::armas_s_update2_sym,
+            (f32, *mut armas_dense, f32, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_mult_diag, generated::armas_s_mult_diag,
+            (*mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_solve_diag, generated::armas_s_solve_diag,
+            (*mut armas_dense, f32, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_qrbuild, generated::armas_s_qrbuild,
+            (*mut armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_qrfactor, generated::armas_s_qrfactor,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_qrmult, generated::armas_s_qrmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_qrsolve, generated::armas_s_qrsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_lqbuild, generated::armas_s_lqbuild,
+            (*mut armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_lqfactor, generated::armas_s_lqfactor,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_lqmult, generated::armas_s_lqmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_lqsolve, generated::armas_s_lqsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_ldlfactor, generated::armas_s_ldlfactor,
+            (*mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_ldlsolve, generated::armas_s_ldlsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_bkfactor, generated::armas_s_bkfactor,
+            (*mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_bksolve, generated::armas_s_bksolve,
+            (*mut armas_dense, *const armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_lufactor, generated::armas_s_lufactor,
+            (*mut armas_dense, *mut armas_pivot, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_lusolve, generated::armas_s_lusolve,
+            (*mut armas_dense, *mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_hessreduce, generated::armas_s_hessreduce,
+            (*mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_hessmult, generated::armas_s_hessmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_cholesky, generated::armas_s_cholesky,
+            (*mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_cholfactor, generated::armas_s_cholfactor,
+            (*mut armas_dense, *mut armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_cholsolve, generated::armas_s_cholsolve,
+            (*mut armas_dense, *const armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_cholupdate, generated::armas_s_cholupdate,
+            (*mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_bdreduce, generated::armas_s_bdreduce,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_bdbuild, generated::armas_s_bdbuild,
+            (*mut armas_dense, *const armas_dense, c_int, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_bdmult, generated::armas_s_bdmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_trdreduce, generated::armas_s_trdreduce,
+            (*mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_trdbuild, generated::armas_s_trdbuild,
+            (*mut armas_dense, *const armas_dense, c_int, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_trdmult, generated::armas_s_trdmult,
+            (*mut armas_dense, *const armas_dense, *const armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_trdeigen, generated::armas_s_trdeigen,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_trdbisect, generated::armas_s_trdbisect,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *const armas_eigen_parameter, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_gvcompute, generated::armas_s_gvcompute,
+            (*mut f32, *mut f32, *mut f32, f32, f32));
+        assert_same_signature!(super::float32::armas_gvrotate, generated::armas_s_gvrotate,
+            (*mut f32, *mut f32, f32, f32, f32, f32));
+        assert_same_signature!(super::float32::armas_gvleft, generated::armas_s_gvleft,
+            (*mut armas_dense, f32, f32, c_int, c_int, c_int, c_int));
+        assert_same_signature!(super::float32::armas_gvright, generated::armas_s_gvright,
+            (*mut armas_dense, f32, f32, c_int, c_int, c_int, c_int));
+        assert_same_signature!(super::float32::armas_gvupdate, generated::armas_s_gvupdate,
+            (*mut armas_dense, c_int, *mut armas_dense, *mut armas_dense, c_int, c_int) -> c_int);
+        assert_same_signature!(super::float32::armas_gvrot_vec, generated::armas_s_gvrot_vec,
+            (*mut armas_dense, *mut armas_dense, f32, f32) -> c_int);
+        assert_same_signature!(super::float32::armas_bdsvd, generated::armas_s_bdsvd,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_svd, generated::armas_s_svd,
+            (*mut armas_dense, *mut armas_dense, *mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_eigen_sym, generated::armas_s_eigen_sym,
+            (*mut armas_dense, *mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_eigen_sym_selected, generated::armas_s_eigen_sym_selected,
+            (*mut armas_dense, *mut armas_dense, *const armas_eigen_parameter, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_luinverse, generated::armas_s_luinverse,
+            (*mut armas_dense, *const armas_pivot, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_cholinverse, generated::armas_s_cholinverse,
+            (*mut armas_dense, c_int, *mut armas_conf) -> c_int);
+        assert_same_signature!(super::float32::armas_ldlinverse, generated::armas_s_ldlinverse,
+            (*mut armas_dense, *const armas_pivot, c_int, *mut armas_conf) -> c_int);
+    }
+
+    // Accelerator handle is opaque pointer in hand written bindings.
+    #[cfg(feature = "accelerators")]
+    #[test]
+    fn test_signatures_accelerators() {
+        use super::armas_ac_handle_t;
+        use generated::armas_ac_handle_t as handle;
+
+        let _: unsafe extern "C" fn(*mut armas_ac_handle_t, c_int) -> c_int = super::armas_ac_init;
+        let _: unsafe extern "C" fn(*mut handle, c_int) -> c_int = generated::armas_ac_init;
+        let _: unsafe extern "C" fn(armas_ac_handle_t) = super::armas_ac_release;
+        let _: unsafe extern "C" fn(handle) = generated::armas_ac_release;
+        let _: unsafe extern "C" fn() -> armas_ac_handle_t = super::armas_ac_default;
+        let _: unsafe extern "C" fn() -> handle = generated::armas_ac_default;
+        let _: unsafe extern "C" fn(armas_ac_handle_t) = super::armas_ac_set_default;
+        let _: unsafe extern "C" fn(handle) = generated::armas_ac_set_default;
+        let _: unsafe extern "C" fn(armas_ac_handle_t, c_int, *mut c_void) -> c_int = super::armas_ac_get;
+        let _: unsafe extern "C" fn(handle, c_int, *mut c_void) -> c_int = generated::armas_ac_get;
+        let _: unsafe extern "C" fn(armas_ac_handle_t, c_int, *mut c_void) -> c_int = super::armas_ac_set;
+        let _: unsafe extern "C" fn(handle, c_int, *mut c_void) -> c_int = generated::armas_ac_set;
+    }
+
+    #[test]
+    fn test_struct_layout() {
+        assert_same_layout!(super::armas_dense, generated::armasd_rs_dense,
+                            elems, step, rows, cols, __data, __nbytes);
+        assert_same_layout!(super::armas_pivot, generated::armasd_rs_pivot,
+                            npivots, indexes, owner);
+        assert_same_layout!(super::armas_conf, generated::armasd_rs_conf,
+                            error, optflags, tolmult, work, accel, maxiter, gmres_m,
+                            numiters, stop, smult, residual);
+        assert_same_layout!(super::armas_eigen_parameter, generated::armasd_rs_eigen_parameter,
+                            ileft, iright, left, right, tau);
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_same_values!(
+            ARMAS_NOTRANS, ARMAS_LOWER, ARMAS_UPPER, ARMAS_SYMM, ARMAS_HERM, ARMAS_UNIT,
+            ARMAS_LEFT, ARMAS_RIGHT, ARMAS_TRANSA, ARMAS_TRANSB, ARMAS_TRANS, ARMAS_CTRANSA,
+            ARMAS_CTRANSB, ARMAS_CTRANS, ARMAS_MULTQ, ARMAS_MULTP, ARMAS_WANTQ, ARMAS_WANTP,
+            ARMAS_WANTU, ARMAS_WANTV, ARMAS_FORWARD, ARMAS_BACKWARD, ARMAS_ABSA, ARMAS_ABSB,
+            ARMAS_ABS, ARMAS_CONJA, ARMAS_CONJB, ARMAS_CONJ, ARMAS_HHNEGATIVE, ARMAS_NONNEG);
+        assert_same_values!(
+            ARMAS_PIVOT_FORWARD, ARMAS_PIVOT_BACKWARD, ARMAS_PIVOT_ROWS, ARMAS_PIVOT_COLS,
+            ARMAS_PIVOT_UPPER, ARMAS_PIVOT_LOWER);
+        assert_same_values!(
+            ARMAS_ONAIVE, ARMAS_OKAHAN, ARMAS_OPAIRWISE, ARMAS_ORECURSIVE, ARMAS_OBLAS_RECURSIVE,
+            ARMAS_OBLAS_BLOCKED, ARMAS_OBLAS_TILED, ARMAS_OSCHED_ROUNDROBIN, ARMAS_OSCHED_RANDOM,
+            ARMAS_OSCHED_TWO, ARMAS_OBSVD_GOLUB, ARMAS_OBSVD_DEMMEL, ARMAS_OABSTOL,
+            ARMAS_OEXTPREC, ARMAS_ONONNEG, ARMAS_CBUF_THREAD, ARMAS_CBUF_LOCAL);
+        assert_same_values!(
+            ARMAS_ESIZE, ARMAS_ENEED_VECTOR, ARMAS_EINVAL, ARMAS_EIMP, ARMAS_EWORK,
+            ARMAS_ESINGULAR, ARMAS_ENEGATIVE, ARMAS_EMEMORY, ARMAS_ECONVERGE, ARMAS_ESVD_FACT,
+            ARMAS_ESVD_LEFT, ARMAS_ESVD_RIGHT, ARMAS_ESVD_EIGEN);
+        assert_same_values!(ARMAS_NORM_ONE, ARMAS_NORM_TWO, ARMAS_NORM_INF, ARMAS_NORM_FRB);
+    }
+}
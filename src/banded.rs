@@ -0,0 +1,383 @@
+//! Tridiagonal and banded matrix storage and solvers.
+//!
+//! Matrices are square. Linear systems are solved without forming the dense matrix,
+//! tridiagonal systems with the Thomas algorithm and banded systems with banded LU
+//! factorization with partial pivoting.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use super::{OpCodes, CopyOps, Error, lapack};
+use super::dense::{Matrix, AsMatrixMut};
+use super::vec::{Vector};
+use super::scalar::{Scalar};
+
+/// Tridiagonal matrix stored as subdiagonal, diagonal and superdiagonal vectors.
+#[derive(Debug, Clone)]
+pub struct Tridiagonal<T: Scalar = f64> {
+    lower: Vector<T>,
+    diag: Vector<T>,
+    upper: Vector<T>
+}
+
+/// Banded matrix with kl subdiagonals and ku superdiagonals.
+///
+/// Elements are stored in (kl+ku+1)-by-n matrix with column j of the band in column
+/// j of the storage and element A[i, j] in storage row ku+i-j.
+#[derive(Debug, Clone)]
+pub struct Banded<T: Scalar = f64> {
+    kl: u32,
+    ku: u32,
+    n: u32,
+    band: Matrix<T>
+}
+
+impl<T: Scalar> Tridiagonal<T> {
+    /// Create tridiagonal matrix from subdiagonal, diagonal and superdiagonal. Off-diagonals
+    /// must be one element shorter than the diagonal.
+    pub fn new(lower: Vector<T>, diag: Vector<T>, upper: Vector<T>) -> Result<Tridiagonal<T>, Error> {
+        let n = diag.size();
+        let m = n.saturating_sub(1);
+        if lower.size() != m || upper.size() != m {
+            return Err(Error::ESize);
+        }
+        Ok(Tridiagonal { lower, diag, upper })
+    }
+
+    /// Create tridiagonal matrix from the three main diagonals of square matrix.
+    pub fn from_matrix(A: &Matrix<T>) -> Result<Tridiagonal<T>, Error> {
+        let (rows, cols) = A.size();
+        if rows != cols {
+            return Err(Error::ESize);
+        }
+        let m = rows.saturating_sub(1);
+        let lower = Vector::new_from((0..m).map(|k| A.get(k + 1, k)).collect());
+        let upper = Vector::new_from((0..m).map(|k| A.get(k, k + 1)).collect());
+        Tridiagonal::new(lower, A.diagonal(0).to_vector(), upper)
+    }
+
+    /// Create dense matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let n = self.size();
+        let mut A = Matrix::new(n, n);
+        for k in 0..n {
+            A.set(k, k, self.diag.get(k));
+            if k + 1 < n {
+                A.set(k + 1, k, self.lower.get(k));
+                A.set(k, k + 1, self.upper.get(k));
+            }
+        }
+        A
+    }
+
+    /// Number of rows and columns.
+    pub fn size(&self) -> u32 {
+        self.diag.size()
+    }
+
+    pub fn lower(&self) -> &Vector<T> {
+        &self.lower
+    }
+
+    pub fn diag(&self) -> &Vector<T> {
+        &self.diag
+    }
+
+    pub fn upper(&self) -> &Vector<T> {
+        &self.upper
+    }
+
+    /// Compute y = alpha*y + beta*A*x
+    pub fn mvmult(&self, alpha: T, y: &mut Vector<T>, beta: T, x: &Vector<T>) -> Result<(), Error> {
+        let n = self.size();
+        if x.size() != n || y.size() != n {
+            return Err(Error::ESize);
+        }
+        for i in 0..n {
+            let mut s = self.diag.get(i) * x.get(i);
+            if i > 0 {
+                s += self.lower.get(i - 1) * x.get(i - 1);
+            }
+            if i + 1 < n {
+                s += self.upper.get(i) * x.get(i + 1);
+            }
+            y.set(i, alpha * y.get(i) + beta * s);
+        }
+        Ok(())
+    }
+
+    /// Solve A*x = b with the Thomas algorithm, b is overwritten with the solution.
+    /// Algorithm does not pivot and fails with ESingular on zero pivot.
+    pub fn solve_vector(&self, b: &mut Vector<T>) -> Result<(), Error> {
+        let n = self.size();
+        if b.size() != n {
+            return Err(Error::ESize);
+        }
+        if n == 0 {
+            return Ok(());
+        }
+        // modified superdiagonal
+        let mut c = vec![T::zero(); n as usize];
+        let mut p = self.diag.get(0);
+        for i in 0..n {
+            if i > 0 {
+                let l = self.lower.get(i - 1);
+                p = self.diag.get(i) - l * c[i as usize - 1];
+                b.set(i, b.get(i) - l * b.get(i - 1));
+            }
+            if p == T::zero() {
+                return Err(Error::ESingular);
+            }
+            if i + 1 < n {
+                c[i as usize] = self.upper.get(i) / p;
+            }
+            b.set(i, b.get(i) / p);
+        }
+        for i in (0..n - 1).rev() {
+            b.set(i, b.get(i) - c[i as usize] * b.get(i + 1));
+        }
+        Ok(())
+    }
+
+    /// Solve A*X = B, B is overwritten with the solution.
+    pub fn solve(&self, B: &mut Matrix<T>) -> Result<(), Error> {
+        for mut b in B.columns_mut() {
+            self.solve_vector(b.as_vector_mut())?;
+        }
+        Ok(())
+    }
+
+    /// Compute eigenvalues of symmetric tridiagonal matrix.
+    pub fn eigenvalues(&self) -> Result<Vector<T>, Error> {
+        let (mut d, mut e) = self.symmetric_diagonals()?;
+        let mut V = Matrix::new(0, 0);
+        lapack::trdeigen(&mut d, &mut e, &mut V, None)?;
+        Ok(d)
+    }
+
+    /// Compute eigenvalues and eigenvectors of symmetric tridiagonal matrix. Eigenvectors
+    /// are written to columns of n-by-n matrix or matrix view V.
+    pub fn eigen_sym(&self, V: &mut impl AsMatrixMut<T>) -> Result<Vector<T>, Error> {
+        let n = self.size();
+        if V.size() != (n, n) {
+            return Err(Error::ESize);
+        }
+        let (mut d, mut e) = self.symmetric_diagonals()?;
+        Matrix::identity(n).copy_to(V, CopyOps::All);
+        lapack::trdeigen(&mut d, &mut e, V, Some(OpCodes::WANTV))?;
+        Ok(d)
+    }
+
+    // Copy diagonal and off-diagonal of symmetric matrix, fails with EInval if not symmetric.
+    fn symmetric_diagonals(&self) -> Result<(Vector<T>, Vector<T>), Error> {
+        if self.lower.iter().zip(self.upper.iter()).any(|((_, l), (_, u))| l != u) {
+            return Err(Error::EInval);
+        }
+        Ok((self.diag.clone(), self.lower.clone()))
+    }
+}
+
+impl<T: Scalar> Banded<T> {
+    /// Create zero n-by-n banded matrix with kl subdiagonals and ku superdiagonals.
+    pub fn new(n: u32, kl: u32, ku: u32) -> Banded<T> {
+        Banded { kl, ku, n, band: Matrix::new(kl + ku + 1, n) }
+    }
+
+    /// Create banded matrix from square matrix. Elements outside the band are ignored.
+    pub fn from_matrix(A: &Matrix<T>, kl: u32, ku: u32) -> Result<Banded<T>, Error> {
+        let (rows, cols) = A.size();
+        if rows != cols {
+            return Err(Error::ESize);
+        }
+        let mut B = Banded::new(rows, kl, ku);
+        for j in 0..cols {
+            for i in B.col_range(j) {
+                B.band.set(ku + i - j, j, A.get(i, j));
+            }
+        }
+        Ok(B)
+    }
+
+    /// Create dense matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut A = Matrix::new(self.n, self.n);
+        for j in 0..self.n {
+            for i in self.col_range(j) {
+                A.set(i, j, self.band.get(self.ku + i - j, j));
+            }
+        }
+        A
+    }
+
+    /// Number of rows and columns.
+    pub fn size(&self) -> u32 {
+        self.n
+    }
+
+    /// Number of subdiagonals.
+    pub fn kl(&self) -> u32 {
+        self.kl
+    }
+
+    /// Number of superdiagonals.
+    pub fn ku(&self) -> u32 {
+        self.ku
+    }
+
+    /// Band storage matrix.
+    pub fn band(&self) -> &Matrix<T> {
+        &self.band
+    }
+
+    /// Get element at [i, j]. Elements outside the band are zero.
+    pub fn get(&self, i: u32, j: u32) -> T {
+        if self.in_band(i, j) {
+            self.band.get(self.ku + i - j, j)
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Set element at [i, j]. Panics if element is outside the band.
+    pub fn set(&mut self, i: u32, j: u32, value: T) {
+        assert!(self.in_band(i, j), "element outside the band");
+        self.band.set(self.ku + i - j, j, value);
+    }
+
+    fn in_band(&self, i: u32, j: u32) -> bool {
+        i < self.n && j < self.n && j <= i + self.ku && i <= j + self.kl
+    }
+
+    // Rows of column j inside the band.
+    fn col_range(&self, j: u32) -> std::ops::Range<u32> {
+        j.saturating_sub(self.ku)..(j + self.kl + 1).min(self.n)
+    }
+
+    /// Compute y = alpha*y + beta*A*x
+    pub fn mvmult(&self, alpha: T, y: &mut Vector<T>, beta: T, x: &Vector<T>) -> Result<(), Error> {
+        if x.size() != self.n || y.size() != self.n {
+            return Err(Error::ESize);
+        }
+        let mut ax = vec![T::zero(); self.n as usize];
+        for j in 0..self.n {
+            let xj = x.get(j);
+            for i in self.col_range(j) {
+                ax[i as usize] += self.band.get(self.ku + i - j, j) * xj;
+            }
+        }
+        for i in 0..self.n {
+            y.set(i, alpha * y.get(i) + beta * ax[i as usize]);
+        }
+        Ok(())
+    }
+
+    /// Solve A*X = B with banded LU factorization with partial pivoting, B is overwritten
+    /// with the solution.
+    pub fn solve(&self, B: &mut Matrix<T>) -> Result<(), Error> {
+        let (rows, _) = B.size();
+        if rows != self.n {
+            return Err(Error::ESize);
+        }
+        let lu = BandLU::factor(self)?;
+        for mut b in B.columns_mut() {
+            lu.solve(b.as_vector_mut());
+        }
+        Ok(())
+    }
+
+    /// Solve A*x = b, b is overwritten with the solution.
+    pub fn solve_vector(&self, b: &mut Vector<T>) -> Result<(), Error> {
+        if b.size() != self.n {
+            return Err(Error::ESize);
+        }
+        BandLU::factor(self)?.solve(b);
+        Ok(())
+    }
+}
+
+// Banded LU factorization. Row interchanges widen the upper band to kl+ku
+// superdiagonals, element U[i, j] is stored at data[kl+ku+i-j + j*ld].
+struct BandLU<T: Scalar> {
+    kl: usize,
+    kv: usize,
+    n: usize,
+    ld: usize,
+    data: Vec<T>,
+    pivots: Vec<usize>
+}
+
+impl<T: Scalar> BandLU<T> {
+    fn factor(A: &Banded<T>) -> Result<BandLU<T>, Error> {
+        let (kl, ku, n) = (A.kl as usize, A.ku as usize, A.n as usize);
+        let kv = kl + ku;
+        let ld = kl + kv + 1;
+        let mut lu = BandLU { kl, kv, n, ld, data: vec![T::zero(); ld * n], pivots: vec![0; n] };
+        for j in 0..A.n {
+            for i in A.col_range(j) {
+                *lu.at(i as usize, j as usize) = A.band.get(A.ku + i - j, j);
+            }
+        }
+
+        for k in 0..n {
+            let last = (k + kl).min(n - 1);
+            let mut p = k;
+            for i in k + 1..=last {
+                if lu.at(i, k).abs() > lu.at(p, k).abs() {
+                    p = i;
+                }
+            }
+            lu.pivots[k] = p;
+            let pivot = *lu.at(p, k);
+            if pivot == T::zero() {
+                return Err(Error::ESingular);
+            }
+            let jlast = (k + kv).min(n - 1);
+            if p != k {
+                for j in k..=jlast {
+                    let t = *lu.at(k, j);
+                    *lu.at(k, j) = *lu.at(p, j);
+                    *lu.at(p, j) = t;
+                }
+            }
+            for i in k + 1..=last {
+                let l = *lu.at(i, k) / pivot;
+                *lu.at(i, k) = l;
+                for j in k + 1..=jlast {
+                    let u = *lu.at(k, j);
+                    *lu.at(i, j) -= l * u;
+                }
+            }
+        }
+        Ok(lu)
+    }
+
+    fn at(&mut self, i: usize, j: usize) -> &mut T {
+        &mut self.data[self.kv + i - j + j * self.ld]
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        self.data[self.kv + i - j + j * self.ld]
+    }
+
+    fn solve(&self, b: &mut Vector<T>) {
+        let n = self.n;
+        let mut x: Vec<T> = b.iter().map(|(_, v)| v).collect();
+        for k in 0..n {
+            x.swap(k, self.pivots[k]);
+            for i in k + 1..=(k + self.kl).min(n - 1) {
+                let xk = x[k];
+                x[i] -= self.get(i, k) * xk;
+            }
+        }
+        for k in (0..n).rev() {
+            let mut s = x[k];
+            for (j, xj) in x.iter().enumerate().take((k + self.kv + 1).min(n)).skip(k + 1) {
+                s -= self.get(k, j) * *xj;
+            }
+            x[k] = s / self.get(k, k);
+        }
+        for (k, v) in x.into_iter().enumerate() {
+            b.set(k as u32, v);
+        }
+    }
+}
@@ -4,13 +4,14 @@
 
 use libarmasd_sys as ffi;
 use super::{OpCodes, Norms};
-use super::dense::{Matrix};
-use super::vec::{Vector};
+use super::dense::{AsMatrix, AsMatrixMut};
+use super::vec::{AsVector, AsVectorMut};
+use super::scalar::{Scalar};
 
 /// Scale vector, x = alpha * x
-pub fn scale(x: &mut Vector, alpha: f64) -> Result<&mut Vector, i32> {
+pub fn scale<T: Scalar, V: AsVectorMut<T>>(x: &mut V, alpha: T) -> Result<&mut V, i32> {
     unsafe {
-        match ffi::armas_scale(x.as_mut_ptr(), alpha, ffi::armas_conf_default()) {
+        match T::armas_scale(x.as_mut_ptr(), alpha, ffi::armas_conf_default()) {
             0 => Ok(x),
             x => Err(-x)
         }
@@ -18,10 +19,10 @@ pub fn scale(x: &mut Vector, alpha: f64) -> Result<&mut Vector, i32> {
 }
 
 /// Scale matrix, A = alpha * A
-pub fn mscale(A: &mut Matrix, alpha: f64, ops: Option<OpCodes>) -> Result<&mut Matrix, i32> {
+pub fn mscale<T: Scalar, M: AsMatrixMut<T>>(A: &mut M, alpha: T, ops: Option<OpCodes>) -> Result<&mut M, i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mscale(A.as_mut_ptr(), alpha, bits, ffi::armas_conf_default()) {
+        match T::armas_mscale(A.as_mut_ptr(), alpha, bits, ffi::armas_conf_default()) {
             0 => Ok(A),
             x => Err(-x)
         }
@@ -29,10 +30,10 @@ pub fn mscale(A: &mut Matrix, alpha: f64, ops: Option<OpCodes>) -> Result<&mut M
 }
 
 /// Add constant to matrix, A = A + alpha
-pub fn madd(A: &mut Matrix, alpha: f64, ops: Option<OpCodes>) -> Result<&mut Matrix, i32> {
+pub fn madd<T: Scalar, M: AsMatrixMut<T>>(A: &mut M, alpha: T, ops: Option<OpCodes>) -> Result<&mut M, i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_madd(A.as_mut_ptr(), alpha, bits, ffi::armas_conf_default()) {
+        match T::armas_madd(A.as_mut_ptr(), alpha, bits, ffi::armas_conf_default()) {
             0 => Ok(A),
             x => Err(-x)
         }
@@ -40,10 +41,10 @@ pub fn madd(A: &mut Matrix, alpha: f64, ops: Option<OpCodes>) -> Result<&mut Mat
 }
 
 /// Element wise addition of matrices, A = alpha*A + beta*B
-pub fn mplus<'a, 'b>(alpha: f64, A: &'a mut Matrix, beta: f64, B: &'b Matrix, ops: Option<OpCodes>) -> Result<&'a mut Matrix, i32> {
+pub fn mplus<'a, 'b, T: Scalar, M: AsMatrixMut<T>>(alpha: T, A: &'a mut M, beta: T, B: &'b impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<&'a mut M, i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mplus(alpha, A.as_mut_ptr(), beta, B.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mplus(alpha, A.as_mut_ptr(), beta, B.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(A),
             x => Err(-x)
         }
@@ -51,17 +52,17 @@ pub fn mplus<'a, 'b>(alpha: f64, A: &'a mut Matrix, beta: f64, B: &'b Matrix, op
 }
 
 /// Compute inner product of two vectors.
-pub fn dot(x: &Vector, y: &Vector) -> f64 {
+pub fn dot<T: Scalar>(x: &impl AsVector<T>, y: &impl AsVector<T>) -> T {
     unsafe {
-        ffi::armas_dot(&x.data, &y.data, ffi::armas_conf_default())
+        T::armas_dot(x.as_ptr(), y.as_ptr(), ffi::armas_conf_default())
     }
 }
 
 /// Compute  result = initial + alpha*x^T*y
-pub fn adot(initial: f64, alpha: f64, x: &Vector, y: &Vector) -> Result<f64, i32> {
-    let mut value: f64 = initial;
+pub fn adot<T: Scalar>(initial: T, alpha: T, x: &impl AsVector<T>, y: &impl AsVector<T>) -> Result<T, i32> {
+    let mut value: T = initial;
     unsafe {
-        match ffi::armas_adot(&mut value, alpha, &x.data, &y.data, ffi::armas_conf_default()) {
+        match T::armas_adot(&mut value, alpha, x.as_ptr(), y.as_ptr(), ffi::armas_conf_default()) {
             0 => Ok(value),
             x => Err(-x)
         }
@@ -69,27 +70,27 @@ pub fn adot(initial: f64, alpha: f64, x: &Vector, y: &Vector) -> Result<f64, i32
 }
 
 /// Compute Euclidean norm of vector.
-pub fn norm2(x: &Vector) -> Result<f64, i32> {
+pub fn norm2<T: Scalar>(x: &impl AsVector<T>) -> Result<T, i32> {
     unsafe {
         let cf: *mut ffi::armas_conf = ffi::armas_conf_default();
-        let result = ffi::armas_nrm2(&x.data, cf);
+        let result = T::armas_nrm2(x.as_ptr(), cf);
         Ok(result)
     }
 }
 
 /// Compute sum(|a_i|)
-pub fn asum(x: &Vector) -> Result<f64, i32> {
+pub fn asum<T: Scalar>(x: &impl AsVector<T>) -> Result<T, i32> {
     unsafe {
         let cf: *mut ffi::armas_conf = ffi::armas_conf_default();
-        let result = ffi::armas_asum(&x.data, cf);
+        let result = T::armas_asum(x.as_ptr(), cf);
         Ok(result)
     }
 }
 
 /// Index of absolute maximum value
-pub fn iamax(x: &Vector) -> Result<u32, i32> {
+pub fn iamax<T: Scalar>(x: &impl AsVector<T>) -> Result<u32, i32> {
     unsafe {
-        let index = ffi::armas_iamax(&x.data, ffi::armas_conf_default());
+        let index = T::armas_iamax(x.as_ptr(), ffi::armas_conf_default());
         if index < 0 {
             return Err(-index);
         }
@@ -98,9 +99,9 @@ pub fn iamax(x: &Vector) -> Result<u32, i32> {
 }
 
 /// Compute y = beta * y + alpha * x
-pub fn axpby(beta: f64, y: &mut Vector, alpha: f64, x: &Vector) -> Result<(), i32> {
+pub fn axpby<T: Scalar>(beta: T, y: &mut impl AsVectorMut<T>, alpha: T, x: &impl AsVector<T>) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_axpby(beta, &mut y.data, alpha,  &x.data, ffi::armas_conf_default()) {
+        match T::armas_axpby(beta, y.as_mut_ptr(), alpha,  x.as_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -108,10 +109,10 @@ pub fn axpby(beta: f64, y: &mut Vector, alpha: f64, x: &Vector) -> Result<(), i3
 }
 
 /// Compute y = alpha*y + beta*A*x
-pub fn mvmult(alpha: f64, y: &mut Vector, beta: f64, A: &Matrix, x: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mvmult<T: Scalar>(alpha: T, y: &mut impl AsVectorMut<T>, beta: T, A: &impl AsMatrix<T>, x: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mvmult(alpha, y.as_mut_ptr(), beta, A.as_ptr(), x.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mvmult(alpha, y.as_mut_ptr(), beta, A.as_ptr(), x.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -119,10 +120,10 @@ pub fn mvmult(alpha: f64, y: &mut Vector, beta: f64, A: &Matrix, x: &Vector, ops
 }
 
 /// Compute y = alpha*y + beta*A*x where A holds either lower or upper triangular part of symmetric matrix A.
-pub fn mvmult_sym(alpha: f64, y: &mut Vector, beta: f64, A: &Matrix, x: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mvmult_sym<T: Scalar>(alpha: T, y: &mut impl AsVectorMut<T>, beta: T, A: &impl AsMatrix<T>, x: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mvmult_sym(alpha, y.as_mut_ptr(), beta, A.as_ptr(), x.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mvmult_sym(alpha, y.as_mut_ptr(), beta, A.as_ptr(), x.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -130,9 +131,9 @@ pub fn mvmult_sym(alpha: f64, y: &mut Vector, beta: f64, A: &Matrix, x: &Vector,
 }
 
 /// Compute rank update of matrix, A = alpha*A + beta*x*y^T
-pub fn mvupdate(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, y: &Vector) -> Result<(), i32> {
+pub fn mvupdate<T: Scalar>(alpha: T, A: &mut impl AsMatrixMut<T>, beta: T, x: &impl AsVector<T>, y: &impl AsVector<T>) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_mvupdate(alpha, A.as_mut_ptr(), beta, x.as_ptr(),  y.as_ptr(), ffi::armas_conf_default()) {
+        match T::armas_mvupdate(alpha, A.as_mut_ptr(), beta, x.as_ptr(),  y.as_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -140,10 +141,10 @@ pub fn mvupdate(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, y: &Vector) -
 }
 
 /// Compute rank update of symmetric matrix, A = alpha*A + beta*x*x^T
-pub fn mvupdate_sym(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mvupdate_sym<T: Scalar>(alpha: T, A: &mut impl AsMatrixMut<T>, beta: T, x: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mvupdate_sym(alpha, A.as_mut_ptr(), beta, x.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mvupdate_sym(alpha, A.as_mut_ptr(), beta, x.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -151,10 +152,10 @@ pub fn mvupdate_sym(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, ops: Opti
 }
 
 /// Compute rank-2  update of symmetric matrix, A = alpha*A + beta*x*x^T
-pub fn mvupdate2_sym(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, y: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mvupdate2_sym<T: Scalar>(alpha: T, A: &mut impl AsMatrixMut<T>, beta: T, x: &impl AsVector<T>, y: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mvupdate2_sym(alpha, A.as_mut_ptr(), beta, x.as_ptr(), y.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mvupdate2_sym(alpha, A.as_mut_ptr(), beta, x.as_ptr(), y.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -162,10 +163,10 @@ pub fn mvupdate2_sym(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, y: &Vect
 }
 
 /// Compute rank-2  update of triangular matrix, A = alpha*A + beta*x*y^T
-pub fn mvupdate_trm(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, y: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mvupdate_trm<T: Scalar>(alpha: T, A: &mut impl AsMatrixMut<T>, beta: T, x: &impl AsVector<T>, y: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mvupdate_trm(alpha, A.as_mut_ptr(), beta, x.as_ptr(), y.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mvupdate_trm(alpha, A.as_mut_ptr(), beta, x.as_ptr(), y.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -173,10 +174,10 @@ pub fn mvupdate_trm(alpha: f64, A: &mut Matrix, beta: f64, x: &Vector, y: &Vecto
 }
 
 /// Compute x = alpha*A*x or x = alpha*A^T*x, where A is lower (upper) triangular matrix.
-pub fn mvmult_trm(x: &mut Vector, alpha: f64, A: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mvmult_trm<T: Scalar>(x: &mut impl AsVectorMut<T>, alpha: T, A: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mvmult_trm(x.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mvmult_trm(x.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -184,10 +185,10 @@ pub fn mvmult_trm(x: &mut Vector, alpha: f64, A: &Matrix, ops: Option<OpCodes>)
 }
 
 /// Compute x = alpha*A^{-1}*x or x = alpha*A^{-T}*x, where A is lower (upper) triangular matrix.
-pub fn mvsolve_trm(x: &mut Vector, alpha: f64, A: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mvsolve_trm<T: Scalar>(x: &mut impl AsVectorMut<T>, alpha: T, A: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mvmult_trm(x.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mvsolve_trm(x.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -195,11 +196,11 @@ pub fn mvsolve_trm(x: &mut Vector, alpha: f64, A: &Matrix, ops: Option<OpCodes>)
 }
 
 /// Compute norm of a matrix.
-pub fn mnorm(A: &Matrix, ops: Norms) -> Result<f64, i32> {
+pub fn mnorm<T: Scalar>(A: &impl AsMatrix<T>, ops: Norms) -> Result<T, i32> {
     unsafe {
         let mut cf = * ffi::armas_conf_default();
         cf.error = 0;
-        let res: f64 = ffi::armas_mnorm(A.as_ptr(), ops as i32, &mut cf);
+        let res: T = T::armas_mnorm(A.as_ptr(), ops as i32, &mut cf);
         match cf.error {
             0 => Ok(res),
             x => Err(x)
@@ -208,10 +209,10 @@ pub fn mnorm(A: &Matrix, ops: Norms) -> Result<f64, i32> {
 }
 
 /// Compute C = alpha*C + beta*A*B
-pub fn mult(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mult<T: Scalar>(alpha: T, C: &mut impl AsMatrixMut<T>, beta: T, A: &impl AsMatrix<T>, B: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mult(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mult(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -219,10 +220,10 @@ pub fn mult(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix, ops:
 }
 
 /// Compute C = alpha*C + beta*A*B, where A is symmetic matrix with lower (upper) triangular part set.
-pub fn mult_sym(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mult_sym<T: Scalar>(alpha: T, C: &mut impl AsMatrixMut<T>, beta: T, A: &impl AsMatrix<T>, B: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mult_sym(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mult_sym(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -230,10 +231,10 @@ pub fn mult_sym(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix, o
 }
 
 /// Compute B = alpha*A*B or B = alpha*B*A where A is lower (upper) triangular matrix.
-pub fn mult_trm(B: &mut Matrix, alpha: f64, A: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mult_trm<T: Scalar>(B: &mut impl AsMatrixMut<T>, alpha: T, A: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mult_trm(B.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mult_trm(B.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -241,10 +242,10 @@ pub fn mult_trm(B: &mut Matrix, alpha: f64, A: &Matrix, ops: Option<OpCodes>) ->
 }
 
 /// Compute B = alpha*A^{-1}*B or B = alpha*B*A^{-1} where A is lower (upper) triangular matrix.
-pub fn solve_trm(B: &mut Matrix, alpha: f64, A: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn solve_trm<T: Scalar>(B: &mut impl AsMatrixMut<T>, alpha: T, A: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_solve_trm(B.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_solve_trm(B.as_mut_ptr(), alpha, A.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -252,10 +253,10 @@ pub fn solve_trm(B: &mut Matrix, alpha: f64, A: &Matrix, ops: Option<OpCodes>) -
 }
 
 /// Compute C = alpha*C + beta*A*B  where C is lower (upper) tridiagonal matrix
-pub fn update_trm(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn update_trm<T: Scalar>(alpha: T, C: &mut impl AsMatrixMut<T>, beta: T, A: &impl AsMatrix<T>, B: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_update_trm(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_update_trm(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -263,10 +264,10 @@ pub fn update_trm(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix,
 }
 
 /// Compute C = alpha*C + beta*A*A^T  where C is lower (upper) tridiagonal matrix
-pub fn update_sym(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn update_sym<T: Scalar>(alpha: T, C: &mut impl AsMatrixMut<T>, beta: T, A: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_update_sym(alpha, C.as_mut_ptr(), beta, A.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_update_sym(alpha, C.as_mut_ptr(), beta, A.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -274,10 +275,10 @@ pub fn update_sym(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, ops: Option
 }
 
 /// Compute C = alpha*C + beta*A*B  where C is lower (upper) tridiagonal matrix
-pub fn update2_sym(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn update2_sym<T: Scalar>(alpha: T, C: &mut impl AsMatrixMut<T>, beta: T, A: &impl AsMatrix<T>, B: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_update2_sym(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_update2_sym(alpha, C.as_mut_ptr(), beta, A.as_ptr(), B.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -285,10 +286,10 @@ pub fn update2_sym(alpha: f64, C: &mut Matrix, beta: f64, A: &Matrix, B: &Matrix
 }
 
 /// Compute B = alpha*diag(x)*B or B = alpha*B*diag(x)
-pub fn mult_diag(B: &mut Matrix, alpha: f64, x: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn mult_diag<T: Scalar>(B: &mut impl AsMatrixMut<T>, alpha: T, x: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_mult_diag(B.as_mut_ptr(), alpha, x.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_mult_diag(B.as_mut_ptr(), alpha, x.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -296,10 +297,10 @@ pub fn mult_diag(B: &mut Matrix, alpha: f64, x: &Vector, ops: Option<OpCodes>) -
 }
 
 /// Compute B = alpha*A^{-1}*diag(x) or B = alpha*diag(x)*A^{-1}
-pub fn solve_diag(B: &mut Matrix, alpha: f64, x: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn solve_diag<T: Scalar>(B: &mut impl AsMatrixMut<T>, alpha: T, x: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_solve_diag(B.as_mut_ptr(), alpha, x.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_solve_diag(B.as_mut_ptr(), alpha, x.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
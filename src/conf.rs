@@ -0,0 +1,114 @@
+//! Runtime configuration of the armas library.
+//!
+//! All functions in this crate use the library default configuration. Changes made
+//! here are global and unsynchronized, the library reads the configuration without
+//! locking. Setters are therefore unsafe and must be called before any computation is
+//! started in other threads.
+
+use libarmasd_sys as ffi;
+
+use super::{ConfFlags};
+
+#[cfg(feature = "accelerators")]
+use std::ptr;
+#[cfg(feature = "accelerators")]
+use std::os::raw::{c_int, c_void};
+
+/// Get option flags of the default configuration.
+pub fn optflags() -> ConfFlags {
+    unsafe {
+        ConfFlags::from_bits_truncate((*ffi::armas_conf_default()).optflags)
+    }
+}
+
+/// Set option flags of the default configuration.
+///
+/// # Safety
+///
+/// No other thread may run computations or access the configuration during the call.
+pub unsafe fn set_optflags(flags: ConfFlags) {
+    (*ffi::armas_conf_default()).optflags = flags.bits();
+}
+
+/// Enable or disable extended precision dot products and matrix-vector operations.
+/// Has effect only if library is built with the `ext-precision` feature.
+///
+/// # Safety
+///
+/// No other thread may run computations or access the configuration during the call.
+pub unsafe fn set_extended_precision(enable: bool) {
+    let mut flags = optflags();
+    flags.set(ConfFlags::EXTPREC, enable);
+    set_optflags(flags);
+}
+
+/// Accelerator, the worker thread pool for BLAS3 functions.
+#[cfg(feature = "accelerators")]
+pub struct Accelerator {
+    handle: ffi::armas_ac_handle_t
+}
+
+#[cfg(feature = "accelerators")]
+impl Accelerator {
+    /// Create accelerator that runs computations in the calling thread.
+    pub fn serial() -> Result<Accelerator, i32> {
+        Accelerator::init(ffi::ARMAS_AC_SERIAL)
+    }
+
+    /// Create threaded accelerator with the requested number of worker threads.
+    pub fn threaded(workers: u32) -> Result<Accelerator, i32> {
+        let mut ac = Accelerator::init(ffi::ARMAS_AC_THREADED)?;
+        ac.set_workers(workers)?;
+        Ok(ac)
+    }
+
+    fn init(actype: c_int) -> Result<Accelerator, i32> {
+        let mut handle: ffi::armas_ac_handle_t = ptr::null_mut();
+        unsafe {
+            match ffi::armas_ac_init(&mut handle, actype) {
+                0 => Ok(Accelerator { handle }),
+                x => Err(-x)
+            }
+        }
+    }
+
+    /// Number of worker threads.
+    pub fn workers(&self) -> Result<u32, i32> {
+        let mut n: c_int = 0;
+        unsafe {
+            match ffi::armas_ac_get(self.handle, ffi::ARMAS_AC_WORKERS, &mut n as *mut c_int as *mut c_void) {
+                0 => Ok(n as u32),
+                x => Err(-x)
+            }
+        }
+    }
+
+    /// Set number of worker threads.
+    pub fn set_workers(&mut self, workers: u32) -> Result<(), i32> {
+        let mut n = workers as c_int;
+        unsafe {
+            match ffi::armas_ac_set(self.handle, ffi::ARMAS_AC_WORKERS, &mut n as *mut c_int as *mut c_void) {
+                0 => Ok(()),
+                x => Err(-x)
+            }
+        }
+    }
+
+    /// Make this accelerator the library default. The library takes ownership of the
+    /// accelerator, it is not released.
+    pub fn set_default(self) {
+        unsafe {
+            ffi::armas_ac_set_default(self.handle);
+        }
+        std::mem::forget(self);
+    }
+}
+
+#[cfg(feature = "accelerators")]
+impl Drop for Accelerator {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::armas_ac_release(self.handle);
+        }
+    }
+}
@@ -1,90 +1,284 @@
 
-use libarmasd_sys as ffi;
-
 use std::mem;
-use std::convert::TryInto;
-use super::{CopyOps};
-use super::vec::{Vector};
+use std::fmt;
+use std::marker::PhantomData;
+use std::convert::{TryFrom, TryInto};
+use super::{CopyOps, Error, print_options};
+use super::vec::{Vector, VectorView, VectorViewMut};
+use super::scalar::{Scalar, Dense};
 
 use serde::{Serialize, Serializer, Deserialize};
 use serde::ser::{SerializeStruct, SerializeSeq};
 
 #[derive(Deserialize)]
-struct MatrixShadow {
+struct MatrixShadow<T> {
     rows: u32,
     cols: u32,
-    data: Vec<f64>
+    data: Vec<T>
+}
+
+#[derive(Deserialize)]
+#[serde(try_from = "MatrixShadow<T>", bound(deserialize = ""))]
+pub struct Matrix<T: Scalar = f64> {
+    data: Dense<T>,
+    vec: Box<Vec<T>>
+}
+
+/// Immutable matrix view over borrowed column major storage.
+///
+/// Submatrix, row, column and diagonal views of a view borrow the view and are read-only,
+/// immutable view cannot produce a writable alias of the storage:
+///
+/// ```compile_fail
+/// use armasd::dense::MatrixView;
+///
+/// let buf = vec![1.0, 2.0, 3.0, 4.0];
+/// let v: MatrixView = MatrixView::from_slice(&buf, 2, 2, 2).unwrap();
+/// v.diagonal(0).set(0, 5.0);
+/// ```
+///
+/// Views do not outlive the storage:
+///
+/// ```compile_fail
+/// use armasd::dense::MatrixView;
+///
+/// let d = {
+///     let buf = vec![1.0, 2.0, 3.0, 4.0];
+///     let v: MatrixView = MatrixView::from_slice(&buf, 2, 2, 2).unwrap();
+///     v.diagonal(0)
+/// };
+/// ```
+pub struct MatrixView<'a, T: Scalar = f64> {
+    mat: Matrix<T>,
+    _marker: PhantomData<&'a [T]>
+}
+
+/// Mutable matrix view over borrowed column major storage.
+///
+/// View is not a `Matrix` and its descriptor cannot be moved out of the view:
+///
+/// ```compile_fail
+/// use armasd::dense::{Matrix, MatrixViewMut};
+///
+/// let mut buf = vec![1.0, 2.0, 3.0, 4.0];
+/// let mut m: Matrix = Matrix::new(2, 2);
+/// let mut v = MatrixViewMut::from_slice(&mut buf, 2, 2, 2).unwrap();
+/// std::mem::swap(&mut *v, &mut m);
+/// ```
+pub struct MatrixViewMut<'a, T: Scalar = f64> {
+    mat: Matrix<T>,
+    _marker: PhantomData<&'a mut [T]>
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Matrix operand of BLAS and LAPACK functions, implemented by `Matrix`, `MatrixView`
+/// and `MatrixViewMut`.
+pub trait AsMatrix<T: Scalar>: sealed::Sealed {
+    /// Pointer to matrix descriptor.
+    fn as_ptr(&self) -> *const Dense<T>;
+
+    /// Number of rows and columns.
+    fn size(&self) -> (u32, u32);
+}
+
+/// Writable matrix operand, implemented by `Matrix` and `MatrixViewMut`.
+pub trait AsMatrixMut<T: Scalar>: AsMatrix<T> {
+    /// Mutable pointer to matrix descriptor.
+    fn as_mut_ptr(&mut self) -> *mut Dense<T>;
+}
+
+pub struct RowIterator<'a, T: Scalar = f64> {
+    source: &'a Matrix<T>,
+    index: u32
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(from = "MatrixShadow")]
-pub struct Matrix {
-    data: ffi::armas_dense,
-    vec: Box<Vec<f64>>
+pub struct ColumnIterator<'a, T: Scalar = f64> {
+    source: &'a Matrix<T>,
+    index: u32
 }
 
-pub struct MatrixIterator<'a> {
-    source: &'a Matrix,
+/// Iterator over mutable row views, rows are disjoint and may be held at the same time.
+pub struct RowIteratorMut<'a, T: Scalar = f64> {
+    source: &'a Matrix<T>,
+    index: u32
+}
+
+/// Iterator over mutable column views, columns are disjoint and may be held at the same time.
+pub struct ColumnIteratorMut<'a, T: Scalar = f64> {
+    source: &'a Matrix<T>,
+    index: u32
+}
+
+pub struct MatrixIterator<'a, T: Scalar = f64> {
+    source: &'a Matrix<T>,
     index: u32,
     size: u32,
     rows: u32
 }
 
-impl Matrix {
-    pub fn as_ptr(&self) -> *const ffi::armas_dense {
+impl<T: Scalar> Matrix<T> {
+    pub fn as_ptr(&self) -> *const Dense<T> {
         &self.data
     }
 
-    pub fn as_mut_ptr(&mut self) -> *mut ffi::armas_dense {
+    pub fn as_mut_ptr(&mut self) -> *mut Dense<T> {
         &mut self.data
     }
 
-    /// Create new matrix of spesificed size.
-    pub fn new(rows: u32, cols: u32) -> Matrix {
+    /// Create new zero initialized matrix of spesificed size.
+    pub fn new(rows: u32, cols: u32) -> Matrix<T> {
+        Matrix::filled(rows, cols, T::zero())
+    }
+
+    /// Create new zero initialized matrix of spesificed size.
+    pub fn zeros(rows: u32, cols: u32) -> Matrix<T> {
+        Matrix::new(rows, cols)
+    }
+
+    /// Create new matrix with all elements set to one.
+    pub fn ones(rows: u32, cols: u32) -> Matrix<T> {
+        Matrix::filled(rows, cols, T::one())
+    }
+
+    /// Create new matrix with all elements set to value.
+    pub fn filled(rows: u32, cols: u32, value: T) -> Matrix<T> {
+        let count = (rows as usize) * (cols as usize);
+        Matrix::from_vec_unchecked(rows, cols, vec![value; count])
+    }
+
+    /// Create new n-by-n identity matrix.
+    pub fn identity(n: u32) -> Matrix<T> {
+        Matrix::eye(n, n)
+    }
+
+    /// Create new matrix with ones on the main diagonal and zeros elsewhere.
+    pub fn eye(rows: u32, cols: u32) -> Matrix<T> {
+        let mut m = Matrix::new(rows, cols);
+        for k in 0..rows.min(cols) {
+            m.set(k, k, T::one());
+        }
+        m
+    }
+
+    /// Create new square matrix with elements of vector on the main diagonal.
+    pub fn from_diagonal(d: &Vector<T>) -> Matrix<T> {
+        let n = d.size();
+        let mut m = Matrix::new(n, n);
+        for k in 0..n {
+            m.set(k, k, d.get(k));
+        }
+        m
+    }
+
+    /// Create new matrix with uninitialized elements.
+    ///
+    /// # Safety
+    ///
+    /// Caller must write all elements before reading any of them.
+    pub unsafe fn uninit(rows: u32, cols: u32) -> Matrix<T> {
+        let count = (rows as usize) * (cols as usize);
+        // Elements live in the spare capacity and are written through the raw pointer of
+        // the descriptor. Length stays zero, uninitialized memory never becomes values of T.
+        let mut vec: Vec<T> = Vec::with_capacity(count);
+        let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+        T::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, rows as i32, vec.as_mut_ptr());
+        Matrix { data: m.assume_init(), vec: Box::new(vec) }
+    }
+
+    // Wrap vector of exactly rows*cols elements as column major matrix.
+    fn from_vec_unchecked(rows: u32, cols: u32, mut vec: Vec<T>) -> Matrix<T> {
         unsafe {
-            let count: usize = (rows * cols) as usize;
-            let mut vec: Vec<f64> = Vec::with_capacity(count);
-            vec.set_len(count);
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            ffi::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, rows as i32, vec.as_mut_ptr());
+            let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+            T::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, rows as i32, vec.as_mut_ptr());
             Matrix { data: m.assume_init(), vec: Box::new(vec) }
         }
     }
 
-    pub fn new_from(rows: u32, cols: u32, mut vec: Vec<f64>) -> Matrix {
+    /// Create new matrix with provided column major data. If vector too small to hold rows*cols
+    /// elements then zero size matrix is returned. See `try_from_vec` for a checked version.
+    pub fn new_from(rows: u32, cols: u32, mut vec: Vec<T>) -> Matrix<T> {
         unsafe {
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            if ((rows * cols) as usize) < vec.len() {
-                ffi::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, rows as i32, vec.as_mut_ptr());
+            let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+            if (rows as usize) * (cols as usize) <= vec.len() {
+                T::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, rows as i32, vec.as_mut_ptr());
             }
             Matrix { data: m.assume_init(), vec: Box::new(vec) }
         }
     }
 
     /// Create new matrix with provided array as data. If vector too small to hold rows*cols elements
-    /// then zero size matrix is returned.
-    pub fn from_vector(rows: u32, cols: u32, vec: &mut Vec<f64>) -> Matrix {
+    /// then zero size matrix is returned. Result is not tied to lifetime of the vector, prefer
+    /// `MatrixViewMut::from_slice`.
+    pub fn from_vector(rows: u32, cols: u32, vec: &mut Vec<T>) -> Matrix<T> {
         unsafe {
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            if ((rows * cols) as usize) < vec.len() {
-                ffi::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, rows as i32, vec.as_mut_ptr());
+            let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+            if (rows as usize) * (cols as usize) <= vec.len() {
+                T::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, rows as i32, vec.as_mut_ptr());
             }
             Matrix { data: m.assume_init(), vec: Box::new(Vec::new()) }
         }
     }
 
-    pub fn uniform(rows: u32, cols: u32) -> Matrix {
+    /// Create new matrix from column major data. Length of vector must be rows*cols.
+    pub fn try_from_vec(rows: u32, cols: u32, vec: Vec<T>) -> Result<Matrix<T>, Error> {
+        if vec.len() != (rows as usize) * (cols as usize) {
+            return Err(Error::ESize);
+        }
+        Ok(Matrix::from_vec_unchecked(rows, cols, vec))
+    }
+
+    /// Create new matrix from row major data. Length of slice must be rows*cols.
+    pub fn from_row_major(rows: u32, cols: u32, data: &[T]) -> Result<Matrix<T>, Error> {
+        if data.len() != (rows as usize) * (cols as usize) {
+            return Err(Error::ESize);
+        }
+        let mut vec = Vec::with_capacity(data.len());
+        for j in 0..cols as usize {
+            for i in 0..rows as usize {
+                vec.push(data[i*cols as usize + j]);
+            }
+        }
+        Ok(Matrix::from_vec_unchecked(rows, cols, vec))
+    }
+
+    /// Create new matrix from rows. All rows must be of same length.
+    pub fn from_rows(rows: &[&[T]]) -> Result<Matrix<T>, Error> {
+        let ncols = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != ncols) {
+            return Err(Error::ESize);
+        }
+        let mut vec = Vec::with_capacity(rows.len() * ncols);
+        for j in 0..ncols {
+            vec.extend(rows.iter().map(|r| r[j]));
+        }
+        Ok(Matrix::from_vec_unchecked(rows.len() as u32, ncols as u32, vec))
+    }
+
+    /// Create new matrix from columns. All columns must be of same length.
+    pub fn from_columns(cols: &[&[T]]) -> Result<Matrix<T>, Error> {
+        let nrows = cols.first().map_or(0, |c| c.len());
+        if cols.iter().any(|c| c.len() != nrows) {
+            return Err(Error::ESize);
+        }
+        let vec = cols.concat();
+        Ok(Matrix::from_vec_unchecked(nrows as u32, cols.len() as u32, vec))
+    }
+
+    pub fn uniform(rows: u32, cols: u32) -> Matrix<T> {
         let mut m = Matrix::new(rows, cols);
         unsafe {
-            ffi::armas_set_all(m.as_mut_ptr(), ffi::armas_uniform, 0);
+            T::armas_set_all(m.as_mut_ptr(), T::UNIFORM, 0);
         }
         m
     }
 
-    pub fn normal(rows: u32, cols: u32) -> Matrix {
+    pub fn normal(rows: u32, cols: u32) -> Matrix<T> {
         let mut m = Matrix::new(rows, cols);
         unsafe {
-            ffi::armas_set_all(m.as_mut_ptr(), ffi::armas_normal, 0);
+            T::armas_set_all(m.as_mut_ptr(), T::NORMAL, 0);
         }
         m
     }
@@ -93,93 +287,241 @@ impl Matrix {
         (self.data.rows.try_into().unwrap_or(0), self.data.cols.try_into().unwrap_or(0))
     }
 
-    /// Create spesified submatrix view over  matrix. Result matrix shares storage
-    /// with the original matrix and changes on submatrix are visible in the original.
-    pub fn submatrix(&self, row: u32, col: u32, nrows: u32, ncols: u32) -> Matrix {
-        unsafe {
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            ffi::armas_submatrix_unsafe(m.as_mut_ptr(), &self.data,
-                row.try_into().unwrap_or(0),
-                col.try_into().unwrap_or(0),
-                nrows.try_into().unwrap_or(0),
-                ncols.try_into().unwrap_or(0));
-            Matrix { data: m.assume_init(), vec: Box::new(Vec::new()) }
-        }
+    /// Create spesified read-only submatrix view over matrix. View shares storage
+    /// with the original matrix, use `submatrix_mut` to write through the view:
+    ///
+    /// ```compile_fail
+    /// use armasd::{blas, dense::Matrix};
+    ///
+    /// let a: Matrix = Matrix::new(3, 3);
+    /// blas::mscale(&mut a.submatrix(0, 0, 2, 2), 2.0, None).unwrap();
+    /// ```
+    pub fn submatrix(&self, row: u32, col: u32, nrows: u32, ncols: u32) -> MatrixView<'_, T> {
+        MatrixView::new(unsafe { self.alias(row, col, nrows, ncols) })
+    }
+
+    // Create submatrix descriptor sharing storage with self. Caller must tie result to a
+    // borrow of self that permits the intended access. Panics if submatrix is out of bounds.
+    pub(crate) unsafe fn alias(&self, row: u32, col: u32, nrows: u32, ncols: u32) -> Matrix<T> {
+        let (rows, cols) = self.size();
+        assert!(row as u64 + nrows as u64 <= rows as u64 && col as u64 + ncols as u64 <= cols as u64,
+            "submatrix out of bounds");
+        let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+        T::armas_submatrix_unsafe(m.as_mut_ptr(), &self.data, row as i32, col as i32, nrows as i32, ncols as i32);
+        Matrix { data: m.assume_init(), vec: Box::new(Vec::new()) }
+    }
+
+    // Create descriptor of n'th diagonal sharing storage with self, see `alias`.
+    unsafe fn alias_diagonal(&self, n: i32) -> Vector<T> {
+        let (rows, cols) = self.size();
+        assert!(n == 0 || (n > 0 && (n as u32) < cols) || (n < 0 && n.unsigned_abs() < rows),
+            "diagonal out of bounds");
+        let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+        T::armas_diag_unsafe(m.as_mut_ptr(), &self.data, n);
+        Vector { data: m.assume_init(), vec: Box::new(Vec::new()) }
     }
 
-    pub fn copy_to(&self, dst: &mut Matrix, opts: CopyOps) -> &Matrix {
+    // Create descriptor of n'th row sharing storage with self, see `alias`.
+    unsafe fn alias_row(&self, n: u32) -> Vector<T> {
+        let (rows, _) = self.size();
+        assert!(n < rows, "row out of bounds");
+        let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+        T::armas_row_unsafe(m.as_mut_ptr(), &self.data, n as i32);
+        Vector { data: m.assume_init(), vec: Box::new(Vec::new()) }
+    }
+
+    // Create descriptor of n'th column sharing storage with self, see `alias`.
+    unsafe fn alias_column(&self, n: u32) -> Vector<T> {
+        let (_, cols) = self.size();
+        assert!(n < cols, "column out of bounds");
+        let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+        T::armas_column_unsafe(m.as_mut_ptr(), &self.data, n as i32);
+        Vector { data: m.assume_init(), vec: Box::new(Vec::new()) }
+    }
+
+    pub fn copy_to<M: AsMatrixMut<T>>(&self, dst: &mut M, opts: CopyOps) -> &Matrix<T> {
         if self.size() != dst.size() {
             return &self;
         }
         unsafe {
-            ffi::armas_mcopy(&mut dst.data, &self.data, opts as i32);
+            T::armas_mcopy(dst.as_mut_ptr(), &self.data, opts as i32);
         }
         self
     }
 
-    /// Create diagonal view over original matrix. Negative n means n'th subdiagonal and
-    /// positive n meahs n'th superdiagonal. Zero n mean main diagonal.
-    pub fn diagonal(&self, n: i32) -> Vector {
-        unsafe {
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            ffi::armas_diag_unsafe(m.as_mut_ptr(), &self.data, n);
-            Vector { data: m.assume_init(), vec: Box::new(Vec::new()) }
-        }
+    /// Create read-only view of diagonal. Negative n means n'th subdiagonal and
+    /// positive n meahs n'th superdiagonal. Zero n mean main diagonal. Panics if
+    /// diagonal is out of bounds.
+    pub fn diagonal(&self, n: i32) -> VectorView<'_, T> {
+        VectorView::new(unsafe { self.alias_diagonal(n) })
     }
 
-    /// Create a row vector view of n'th row  in the original matrix.
-    pub fn row(&self, n: u32) -> Vector {
-        unsafe {
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            ffi::armas_row_unsafe(m.as_mut_ptr(), &self.data, n.try_into().unwrap_or(0));
-            Vector { data: m.assume_init(), vec: Box::new(Vec::new()) }
-        }
+    /// Create read-only view of n'th row. Panics if n is out of bounds.
+    pub fn row(&self, n: u32) -> VectorView<'_, T> {
+        VectorView::new(unsafe { self.alias_row(n) })
     }
 
-    /// Create a column vector view of n'th column in the original matrix.
-    pub fn column(&self, n: i32) -> Vector {
-        unsafe {
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            ffi::armas_row_unsafe(m.as_mut_ptr(), &self.data, n);
-            Vector { data: m.assume_init(), vec: Box::new(Vec::new()) }
-        }
+    /// Create read-only view of n'th column. Panics if n is out of bounds. View cannot
+    /// write the matrix or outlive it:
+    ///
+    /// ```compile_fail
+    /// use armasd::dense::Matrix;
+    ///
+    /// let a: Matrix = Matrix::new(2, 2);
+    /// a.column(0).set(0, 1.0);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use armasd::dense::Matrix;
+    ///
+    /// let c = {
+    ///     let m: Matrix = Matrix::new(2, 2);
+    ///     m.column(0)
+    /// };
+    /// c.get(0);
+    /// ```
+    pub fn column(&self, n: u32) -> VectorView<'_, T> {
+        VectorView::new(unsafe { self.alias_column(n) })
     }
 
     /// Get element at [i, j]
-    pub fn get(&self, i: u32, j: u32) -> f64 {
+    pub fn get(&self, i: u32, j: u32) -> T {
         unsafe {
-            ffi::armas_get_unsafe(&self.data, i.try_into().unwrap_or(0), j.try_into().unwrap_or(0))
+            T::armas_get_unsafe(&self.data, i.try_into().unwrap_or(0), j.try_into().unwrap_or(0))
         }
     }
 
     /// Set element at [i, j]
-    pub fn set(&mut self, i: u32, j: u32, value: f64) {
+    pub fn set(&mut self, i: u32, j: u32, value: T) {
         unsafe {
-            ffi::armas_set_unsafe(&mut self.data, i.try_into().unwrap_or(0), j.try_into().unwrap_or(0), value);
+            T::armas_set_unsafe(&mut self.data, i.try_into().unwrap_or(0), j.try_into().unwrap_or(0), value);
         }
     }
 
-    pub fn set_all(&mut self, func: extern fn() -> f64) {
+    pub fn set_all(&mut self, func: extern fn() -> T) {
         unsafe {
-            ffi::armas_set_all(&mut self.data, func, 0);
+            T::armas_set_all(&mut self.data, func, 0);
         }
     }
 
-    pub fn iter(&self) -> MatrixIterator {
+    pub fn iter(&self) -> MatrixIterator<T> {
         let (rows, cols) = self.size();
         MatrixIterator { source: self, index: 0, rows: rows, size: rows*cols }
     }
+
+    /// Iterate over read-only row views of the matrix.
+    pub fn rows(&self) -> RowIterator<'_, T> {
+        RowIterator { source: self, index: 0 }
+    }
+
+    /// Iterate over read-only column views of the matrix.
+    pub fn columns(&self) -> ColumnIterator<'_, T> {
+        ColumnIterator { source: self, index: 0 }
+    }
+
+    /// Create read-only view of rows [start, end). Use `row_range_mut` to write the rows:
+    ///
+    /// ```compile_fail
+    /// use armasd::dense::Matrix;
+    ///
+    /// let a: Matrix = Matrix::new(3, 3);
+    /// a.row_range(0, 1).set(0, 0, 1.0);
+    /// ```
+    pub fn row_range(&self, start: u32, end: u32) -> MatrixView<'_, T> {
+        let (_, cols) = self.size();
+        assert!(start <= end, "row range out of bounds");
+        MatrixView::new(unsafe { self.alias(start, 0, end - start, cols) })
+    }
+
+    /// Create read-only view of columns [start, end).
+    pub fn column_range(&self, start: u32, end: u32) -> MatrixView<'_, T> {
+        let (rows, _) = self.size();
+        assert!(start <= end, "column range out of bounds");
+        MatrixView::new(unsafe { self.alias(0, start, rows, end - start) })
+    }
+
+    /// Create mutable submatrix view borrowed from the matrix.
+    pub fn submatrix_mut(&mut self, row: u32, col: u32, nrows: u32, ncols: u32) -> MatrixViewMut<'_, T> {
+        MatrixViewMut::new(unsafe { self.alias(row, col, nrows, ncols) })
+    }
+
+    /// Create mutable view of rows [start, end).
+    pub fn row_range_mut(&mut self, start: u32, end: u32) -> MatrixViewMut<'_, T> {
+        let (_, cols) = self.size();
+        assert!(start <= end, "row range out of bounds");
+        self.submatrix_mut(start, 0, end - start, cols)
+    }
+
+    /// Create mutable view of columns [start, end).
+    pub fn column_range_mut(&mut self, start: u32, end: u32) -> MatrixViewMut<'_, T> {
+        let (rows, _) = self.size();
+        assert!(start <= end, "column range out of bounds");
+        self.submatrix_mut(0, start, rows, end - start)
+    }
+
+    /// Create mutable view of n'th diagonal, see `diagonal`.
+    pub fn diagonal_mut(&mut self, n: i32) -> VectorViewMut<'_, T> {
+        VectorViewMut::new(unsafe { self.alias_diagonal(n) })
+    }
+
+    /// Create mutable view of n'th row.
+    pub fn row_mut(&mut self, n: u32) -> VectorViewMut<'_, T> {
+        VectorViewMut::new(unsafe { self.alias_row(n) })
+    }
+
+    /// Create mutable view of n'th column.
+    pub fn column_mut(&mut self, n: u32) -> VectorViewMut<'_, T> {
+        VectorViewMut::new(unsafe { self.alias_column(n) })
+    }
+
+    /// Iterate over mutable row views of the matrix.
+    pub fn rows_mut(&mut self) -> RowIteratorMut<'_, T> {
+        RowIteratorMut { source: self, index: 0 }
+    }
+
+    /// Iterate over mutable column views of the matrix.
+    pub fn columns_mut(&mut self) -> ColumnIteratorMut<'_, T> {
+        ColumnIteratorMut { source: self, index: 0 }
+    }
+
+    // Copy elements of matrix operand to new matrix.
+    pub(crate) fn copy_from(a: &impl AsMatrix<T>) -> Matrix<T> {
+        let (rows, cols) = a.size();
+        let mut m = Matrix::new(rows, cols);
+        unsafe {
+            T::armas_mcopy(&mut m.data, a.as_ptr(), CopyOps::All as i32);
+        }
+        m
+    }
+
+    /// Split matrix to disjoint mutable views of rows [0, row) and [row, rows).
+    pub fn split_at_row(&mut self, row: u32) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>) {
+        let (rows, cols) = self.size();
+        assert!(row <= rows, "split row out of bounds");
+        // Halves are disjoint and both borrow self mutably.
+        let (top, bottom) = unsafe { (self.alias(0, 0, row, cols), self.alias(row, 0, rows - row, cols)) };
+        (MatrixViewMut::new(top), MatrixViewMut::new(bottom))
+    }
+
+    /// Split matrix to disjoint mutable views of columns [0, col) and [col, cols).
+    pub fn split_at_col(&mut self, col: u32) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>) {
+        let (rows, cols) = self.size();
+        assert!(col <= cols, "split column out of bounds");
+        // Halves are disjoint and both borrow self mutably.
+        let (left, right) = unsafe { (self.alias(0, 0, rows, col), self.alias(0, col, rows, cols - col)) };
+        (MatrixViewMut::new(left), MatrixViewMut::new(right))
+    }
 }
 
-impl Drop for Matrix {
+impl<T: Scalar> Drop for Matrix<T> {
     fn drop(&mut self) {
         unsafe {
-            ffi::armas_release(&mut self.data);
+            T::armas_release(&mut self.data);
         }
     }
 }
 
-impl Clone for Matrix {
+impl<T: Scalar> Clone for Matrix<T> {
     fn clone(&self) -> Self {
         let (rows, cols) = self.size();
         let mut mat = Matrix::new(rows, cols);
@@ -188,8 +530,268 @@ impl Clone for Matrix {
     }
 }
 
-impl<'a> Iterator for MatrixIterator<'a> {
-    type Item = (u32, u32, f64);
+/// Show shape, leading dimension and whether matrix owns its storage or is a view.
+/// Ownership is derived from allocated storage, zero size matrices are reported as views.
+impl<T: Scalar> fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Matrix")
+            .field("shape", &self.size())
+            .field("stride", &self.data.step)
+            .field("owned", &(self.vec.capacity() > 0))
+            .finish()
+    }
+}
+
+/// Show elements with aligned columns as set by `print_options`.
+impl<T: Scalar> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (rows, cols) = self.size();
+        print_options::write_table(f, rows, cols, |i, j| self.get(i, j))
+    }
+}
+
+// Read-only accessors of matrix views. Views derived from a view borrow it.
+macro_rules! view_accessors {
+    () => {
+        pub fn size(&self) -> (u32, u32) {
+            self.mat.size()
+        }
+
+        /// Get element at [i, j]
+        pub fn get(&self, i: u32, j: u32) -> T {
+            self.mat.get(i, j)
+        }
+
+        pub fn iter(&self) -> MatrixIterator<'_, T> {
+            self.mat.iter()
+        }
+
+        /// Create submatrix view borrowed from this view.
+        pub fn submatrix(&self, row: u32, col: u32, nrows: u32, ncols: u32) -> MatrixView<'_, T> {
+            self.mat.submatrix(row, col, nrows, ncols)
+        }
+
+        /// Create diagonal view borrowed from this view.
+        pub fn diagonal(&self, n: i32) -> VectorView<'_, T> {
+            self.mat.diagonal(n)
+        }
+
+        /// Create view of n'th row borrowed from this view.
+        pub fn row(&self, n: u32) -> VectorView<'_, T> {
+            self.mat.row(n)
+        }
+
+        /// Create view of n'th column borrowed from this view.
+        pub fn column(&self, n: u32) -> VectorView<'_, T> {
+            self.mat.column(n)
+        }
+
+        /// Create view of rows [start, end) borrowed from this view.
+        pub fn row_range(&self, start: u32, end: u32) -> MatrixView<'_, T> {
+            self.mat.row_range(start, end)
+        }
+
+        /// Create view of columns [start, end) borrowed from this view.
+        pub fn column_range(&self, start: u32, end: u32) -> MatrixView<'_, T> {
+            self.mat.column_range(start, end)
+        }
+
+        /// Iterate over read-only row views.
+        pub fn rows(&self) -> RowIterator<'_, T> {
+            self.mat.rows()
+        }
+
+        /// Iterate over read-only column views.
+        pub fn columns(&self) -> ColumnIterator<'_, T> {
+            self.mat.columns()
+        }
+
+        pub fn copy_to<M: AsMatrixMut<T>>(&self, dst: &mut M, opts: CopyOps) -> &Self {
+            self.mat.copy_to(dst, opts);
+            self
+        }
+
+        /// Copy elements to new matrix.
+        pub fn to_matrix(&self) -> Matrix<T> {
+            self.mat.clone()
+        }
+    }
+}
+
+// Check that storage of len elements holds rows-by-cols matrix with leading dimension ld.
+fn check_storage(len: usize, rows: u32, cols: u32, ld: u32) -> Result<(), Error> {
+    if ld < rows.max(1) {
+        return Err(Error::EInval);
+    }
+    if cols > 0 && rows > 0 && len < (ld as usize) * (cols as usize - 1) + rows as usize {
+        return Err(Error::ESize);
+    }
+    Ok(())
+}
+
+// Wrap storage as matrix not owning the storage.
+unsafe fn make_view<T: Scalar>(ptr: *mut T, rows: u32, cols: u32, ld: u32) -> Matrix<T> {
+    let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+    T::armas_make(m.as_mut_ptr(), rows as i32, cols as i32, ld as i32, ptr);
+    Matrix { data: m.assume_init(), vec: Box::new(Vec::new()) }
+}
+
+impl<'a, T: Scalar> MatrixView<'a, T> {
+    /// Create rows-by-cols matrix view over column major slice with leading dimension ld.
+    /// Leading dimension must be at least rows and slice large enough to hold all columns.
+    pub fn from_slice(data: &'a [T], rows: u32, cols: u32, ld: u32) -> Result<MatrixView<'a, T>, Error> {
+        check_storage(data.len(), rows, cols, ld)?;
+        // View gives out only shared references to the matrix.
+        let mat = unsafe { make_view(data.as_ptr() as *mut T, rows, cols, ld) };
+        Ok(MatrixView { mat, _marker: PhantomData })
+    }
+}
+
+impl<'a, T: Scalar> MatrixView<'a, T> {
+    // Wrap view matrix borrowed from a parent matrix.
+    pub(crate) fn new(mat: Matrix<T>) -> MatrixView<'a, T> {
+        MatrixView { mat, _marker: PhantomData }
+    }
+
+    view_accessors!();
+}
+
+impl<'a, T: Scalar> MatrixViewMut<'a, T> {
+    /// Create rows-by-cols mutable matrix view over column major slice with leading dimension ld.
+    /// Leading dimension must be at least rows and slice large enough to hold all columns.
+    pub fn from_slice(data: &'a mut [T], rows: u32, cols: u32, ld: u32) -> Result<MatrixViewMut<'a, T>, Error> {
+        check_storage(data.len(), rows, cols, ld)?;
+        let mat = unsafe { make_view(data.as_mut_ptr(), rows, cols, ld) };
+        Ok(MatrixViewMut { mat, _marker: PhantomData })
+    }
+}
+
+impl<'a, T: Scalar> MatrixViewMut<'a, T> {
+    // Wrap view matrix borrowed from a parent matrix.
+    pub(crate) fn new(mat: Matrix<T>) -> MatrixViewMut<'a, T> {
+        MatrixViewMut { mat, _marker: PhantomData }
+    }
+
+    view_accessors!();
+
+    /// Set element at [i, j]
+    pub fn set(&mut self, i: u32, j: u32, value: T) {
+        self.mat.set(i, j, value)
+    }
+
+    /// Create mutable submatrix view borrowed from this view.
+    pub fn submatrix_mut(&mut self, row: u32, col: u32, nrows: u32, ncols: u32) -> MatrixViewMut<'_, T> {
+        self.mat.submatrix_mut(row, col, nrows, ncols)
+    }
+
+    /// Create mutable view of rows [start, end) borrowed from this view.
+    pub fn row_range_mut(&mut self, start: u32, end: u32) -> MatrixViewMut<'_, T> {
+        self.mat.row_range_mut(start, end)
+    }
+
+    /// Create mutable view of columns [start, end) borrowed from this view.
+    pub fn column_range_mut(&mut self, start: u32, end: u32) -> MatrixViewMut<'_, T> {
+        self.mat.column_range_mut(start, end)
+    }
+
+    /// Iterate over mutable row views borrowed from this view.
+    pub fn rows_mut(&mut self) -> RowIteratorMut<'_, T> {
+        self.mat.rows_mut()
+    }
+
+    /// Iterate over mutable column views borrowed from this view.
+    pub fn columns_mut(&mut self) -> ColumnIteratorMut<'_, T> {
+        self.mat.columns_mut()
+    }
+
+    /// Create mutable diagonal view borrowed from this view.
+    pub fn diagonal_mut(&mut self, n: i32) -> VectorViewMut<'_, T> {
+        self.mat.diagonal_mut(n)
+    }
+
+    /// Create mutable view of n'th row borrowed from this view.
+    pub fn row_mut(&mut self, n: u32) -> VectorViewMut<'_, T> {
+        self.mat.row_mut(n)
+    }
+
+    /// Create mutable view of n'th column borrowed from this view.
+    pub fn column_mut(&mut self, n: u32) -> VectorViewMut<'_, T> {
+        self.mat.column_mut(n)
+    }
+}
+
+impl<T: Scalar> sealed::Sealed for Matrix<T> {}
+impl<'a, T: Scalar> sealed::Sealed for MatrixView<'a, T> {}
+impl<'a, T: Scalar> sealed::Sealed for MatrixViewMut<'a, T> {}
+
+impl<T: Scalar> AsMatrix<T> for Matrix<T> {
+    fn as_ptr(&self) -> *const Dense<T> {
+        &self.data
+    }
+
+    fn size(&self) -> (u32, u32) {
+        Matrix::size(self)
+    }
+}
+
+impl<T: Scalar> AsMatrixMut<T> for Matrix<T> {
+    fn as_mut_ptr(&mut self) -> *mut Dense<T> {
+        &mut self.data
+    }
+}
+
+impl<'a, T: Scalar> AsMatrix<T> for MatrixView<'a, T> {
+    fn as_ptr(&self) -> *const Dense<T> {
+        &self.mat.data
+    }
+
+    fn size(&self) -> (u32, u32) {
+        self.mat.size()
+    }
+}
+
+impl<'a, T: Scalar> AsMatrix<T> for MatrixViewMut<'a, T> {
+    fn as_ptr(&self) -> *const Dense<T> {
+        &self.mat.data
+    }
+
+    fn size(&self) -> (u32, u32) {
+        self.mat.size()
+    }
+}
+
+impl<'a, T: Scalar> AsMatrixMut<T> for MatrixViewMut<'a, T> {
+    fn as_mut_ptr(&mut self) -> *mut Dense<T> {
+        &mut self.mat.data
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.mat, f)
+    }
+}
+
+impl<'a, T: Scalar> fmt::Display for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.mat, f)
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for MatrixViewMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.mat, f)
+    }
+}
+
+impl<'a, T: Scalar> fmt::Display for MatrixViewMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.mat, f)
+    }
+}
+
+impl<'a, T: Scalar> Iterator for MatrixIterator<'a, T> {
+    type Item = (u32, u32, T);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index >= self.size {
@@ -202,11 +804,65 @@ impl<'a> Iterator for MatrixIterator<'a> {
     }
 }
 
-impl<'a> IntoIterator for &'a Matrix {
-    type Item = (u32, u32, f64);
-    type IntoIter = MatrixIterator<'a>;
+impl<'a, T: Scalar> Iterator for RowIterator<'a, T> {
+    type Item = VectorView<'a, T>;
 
-    fn into_iter(self) -> MatrixIterator<'a> {
+    fn next(&mut self) -> Option<Self::Item> {
+        let (rows, _) = self.source.size();
+        if self.index >= rows {
+            return None;
+        }
+        self.index += 1;
+        Some(self.source.row(self.index - 1))
+    }
+}
+
+impl<'a, T: Scalar> Iterator for ColumnIterator<'a, T> {
+    type Item = VectorView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, cols) = self.source.size();
+        if self.index >= cols {
+            return None;
+        }
+        self.index += 1;
+        Some(self.source.column(self.index - 1))
+    }
+}
+
+impl<'a, T: Scalar> Iterator for RowIteratorMut<'a, T> {
+    type Item = VectorViewMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (rows, _) = self.source.size();
+        if self.index >= rows {
+            return None;
+        }
+        self.index += 1;
+        // Rows are disjoint and borrow the matrix mutably through the iterator.
+        Some(VectorViewMut::new(unsafe { self.source.alias_row(self.index - 1) }))
+    }
+}
+
+impl<'a, T: Scalar> Iterator for ColumnIteratorMut<'a, T> {
+    type Item = VectorViewMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, cols) = self.source.size();
+        if self.index >= cols {
+            return None;
+        }
+        self.index += 1;
+        // Columns are disjoint and borrow the matrix mutably through the iterator.
+        Some(VectorViewMut::new(unsafe { self.source.alias_column(self.index - 1) }))
+    }
+}
+
+impl<'a, T: Scalar> IntoIterator for &'a Matrix<T> {
+    type Item = (u32, u32, T);
+    type IntoIter = MatrixIterator<'a, T>;
+
+    fn into_iter(self) -> MatrixIterator<'a, T> {
         self.iter()
     }
 }
@@ -214,7 +870,7 @@ impl<'a> IntoIterator for &'a Matrix {
 /// Serialize matrix elements thought MatrixIterator as the source
 /// matrix may be submatrix view of underlying matrix. Therefore the
 /// matrix elements are not necessary in sequential memory addresses.
-impl<'a> Serialize for MatrixIterator<'a> {
+impl<'a, T: Scalar> Serialize for MatrixIterator<'a, T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -231,7 +887,7 @@ impl<'a> Serialize for MatrixIterator<'a> {
     }
 }
 
-impl Serialize for Matrix {
+impl<T: Scalar> Serialize for Matrix<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -246,8 +902,20 @@ impl Serialize for Matrix {
     }
 }
 
-impl From<MatrixShadow> for Matrix {
-    fn from(m: MatrixShadow) -> Self {
-        Matrix::new_from(m.rows, m.cols, m.data)
+impl<T: Scalar> TryFrom<MatrixShadow<T>> for Matrix<T> {
+    type Error = Error;
+
+    fn try_from(m: MatrixShadow<T>) -> Result<Self, Error> {
+        Matrix::try_from_vec(m.rows, m.cols, m.data)
+    }
+}
+
+/// Create matrix from vector of rows.
+impl<T: Scalar> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = Error;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Error> {
+        let rows: Vec<&[T]> = rows.iter().map(|r| r.as_slice()).collect();
+        Matrix::from_rows(&rows)
     }
 }
@@ -0,0 +1,204 @@
+//! Test matrices for validation and benchmarking.
+//!
+//! Classic matrices with known structure, condition or spectrum. Indexes in formulas are
+//! zero based.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use std::os::raw::c_int;
+
+use super::{blas};
+use super::dense::{Matrix};
+use super::vec::{Vector};
+use super::scalar::{Scalar};
+
+// Create matrix with elements computed from indexes.
+fn from_values<T: Scalar>(rows: u32, cols: u32, func: unsafe extern "C" fn(c_int, c_int) -> T) -> Matrix<T> {
+    let mut A = Matrix::new(rows, cols);
+    unsafe {
+        T::armas_set_values(A.as_mut_ptr(), func, 0);
+    }
+    A
+}
+
+extern "C" fn hilbert_elem<T: Scalar>(i: c_int, j: c_int) -> T {
+    T::one() / T::from_f64((i + j + 1) as f64)
+}
+
+extern "C" fn lehmer_elem<T: Scalar>(i: c_int, j: c_int) -> T {
+    T::from_f64((i.min(j) + 1) as f64 / (i.max(j) + 1) as f64)
+}
+
+extern "C" fn pascal_elem<T: Scalar>(i: c_int, j: c_int) -> T {
+    // binomial(i + j, i)
+    let k = i.min(j);
+    let b = (1..=k).fold(1.0, |acc, l| acc * (i + j - k + l) as f64 / l as f64);
+    T::from_f64(b.round())
+}
+
+/// Hilbert matrix H(i, j) = 1/(i + j + 1), notoriously ill-conditioned.
+pub fn hilbert<T: Scalar>(n: u32) -> Matrix<T> {
+    from_values(n, n, hilbert_elem::<T>)
+}
+
+/// Lehmer matrix L(i, j) = (min(i, j) + 1)/(max(i, j) + 1), symmetric positive definite.
+pub fn lehmer<T: Scalar>(n: u32) -> Matrix<T> {
+    from_values(n, n, lehmer_elem::<T>)
+}
+
+/// Symmetric Pascal matrix P(i, j) = binomial(i + j, i) with determinant one.
+pub fn pascal<T: Scalar>(n: u32) -> Matrix<T> {
+    from_values(n, n, pascal_elem::<T>)
+}
+
+/// Frank matrix, upper Hessenberg with F(i, j) = n - max(i, j) for j >= i - 1. Eigenvalues
+/// are positive and the small ones are ill-conditioned.
+pub fn frank<T: Scalar>(n: u32) -> Matrix<T> {
+    let mut A = Matrix::new(n, n);
+    for j in 0..n {
+        for i in 0..n.min(j + 2) {
+            A.set(i, j, T::from_f64((n - i.max(j)) as f64));
+        }
+    }
+    A
+}
+
+/// Wilkinson matrix W+ of order n, symmetric tridiagonal with diagonal |(n-1)/2 - i| and
+/// ones on off-diagonals. Has pairs of nearly equal eigenvalues.
+pub fn wilkinson<T: Scalar>(n: u32) -> Matrix<T> {
+    let mut A = Matrix::new(n, n);
+    let m = (n as f64 - 1.0) / 2.0;
+    for i in 0..n {
+        A.set(i, i, T::from_f64((m - i as f64).abs()));
+        if i + 1 < n {
+            A.set(i, i + 1, T::one());
+            A.set(i + 1, i, T::one());
+        }
+    }
+    A
+}
+
+/// Kahan matrix, upper triangular with diagonal s^i and elements -c*s^i above diagonal
+/// where c = cos(theta) and s = sin(theta). Ill-conditioned but does not reveal it on diagonal.
+pub fn kahan<T: Scalar>(n: u32, theta: T) -> Matrix<T> {
+    let (s, c) = theta.to_f64().sin_cos();
+    let mut A = Matrix::new(n, n);
+    for i in 0..n {
+        let si = s.powi(i as i32);
+        A.set(i, i, T::from_f64(si));
+        for j in i + 1..n {
+            A.set(i, j, T::from_f64(-c * si));
+        }
+    }
+    A
+}
+
+/// Vandermonde matrix with n columns V(i, j) = x(i)^j.
+pub fn vandermonde<T: Scalar>(x: &Vector<T>, n: u32) -> Matrix<T> {
+    let m = x.size();
+    let mut A = Matrix::new(m, n);
+    for i in 0..m {
+        let mut v = T::one();
+        for j in 0..n {
+            A.set(i, j, v);
+            v *= x.get(i);
+        }
+    }
+    A
+}
+
+/// Toeplitz matrix with first column c and first row r, constant along diagonals. First
+/// element of r is ignored.
+pub fn toeplitz<T: Scalar>(c: &Vector<T>, r: &Vector<T>) -> Matrix<T> {
+    let (m, n) = (c.size(), r.size());
+    let mut A = Matrix::new(m, n);
+    for j in 0..n {
+        for i in 0..m {
+            A.set(i, j, if i >= j { c.get(i - j) } else { r.get(j - i) });
+        }
+    }
+    A
+}
+
+/// Hankel matrix with first column c and last row r, constant along anti-diagonals. First
+/// element of r is ignored.
+pub fn hankel<T: Scalar>(c: &Vector<T>, r: &Vector<T>) -> Matrix<T> {
+    let (m, n) = (c.size(), r.size());
+    let mut A = Matrix::new(m, n);
+    for j in 0..n {
+        for i in 0..m {
+            A.set(i, j, if i + j < m { c.get(i + j) } else { r.get(i + j + 1 - m) });
+        }
+    }
+    A
+}
+
+/// Circulant matrix with first column c, each column is the previous one rotated down.
+pub fn circulant<T: Scalar>(c: &Vector<T>) -> Matrix<T> {
+    let n = c.size();
+    let mut A = Matrix::new(n, n);
+    for j in 0..n {
+        for i in 0..n {
+            A.set(i, j, c.get((i + n - j) % n));
+        }
+    }
+    A
+}
+
+/// Finite difference Laplacian tridiag(-1, 2, -1) of order n with Dirichlet boundary.
+/// Eigenvalues are 2 - 2*cos(k*pi/(n+1)), k = 1..n.
+pub fn laplacian_1d<T: Scalar>(n: u32) -> Matrix<T> {
+    let mut A = Matrix::new(n, n);
+    for i in 0..n {
+        A.set(i, i, T::from_f64(2.0));
+        if i + 1 < n {
+            A.set(i, i + 1, -T::one());
+            A.set(i + 1, i, -T::one());
+        }
+    }
+    A
+}
+
+/// Five point finite difference Laplacian on nx-by-ny grid with Dirichlet boundary, order
+/// nx*ny matrix kron(I, T(nx)) + kron(T(ny), I) with grid points numbered along x first.
+pub fn laplacian_2d<T: Scalar>(nx: u32, ny: u32) -> Matrix<T> {
+    let n = nx * ny;
+    let mut A = Matrix::new(n, n);
+    for y in 0..ny {
+        for x in 0..nx {
+            let k = y * nx + x;
+            A.set(k, k, T::from_f64(4.0));
+            if x + 1 < nx {
+                A.set(k, k + 1, -T::one());
+                A.set(k + 1, k, -T::one());
+            }
+            if y + 1 < ny {
+                A.set(k, k + nx, -T::one());
+                A.set(k + nx, k, -T::one());
+            }
+        }
+    }
+    A
+}
+
+/// Symmetric matrix Q*diag(d)*Q with eigenvalues d. Q = I - (2/n)*e*e^T is symmetric
+/// orthogonal Householder reflector with vector of ones e.
+pub fn with_eigenvalues<T: Scalar>(d: &Vector<T>) -> Matrix<T> {
+    let n = d.size();
+    let mut Q = Matrix::filled(n, n, T::from_f64(-2.0 / n as f64));
+    blas::axpby(T::one(), &mut Q.diagonal_mut(0), T::one(), &Vector::ones(n)).expect("reflector failed");
+    let mut QD = Q.clone();
+    for k in 0..n {
+        blas::scale(&mut QD.column_mut(k), d.get(k)).expect("scaling failed");
+    }
+    let mut A = Matrix::new(n, n);
+    blas::mult(T::zero(), &mut A, T::one(), &QD, &Q, None).expect("product failed");
+    // symmetrize rounding errors
+    for j in 0..n {
+        for i in j + 1..n {
+            A.set(i, j, A.get(j, i));
+        }
+    }
+    A
+}
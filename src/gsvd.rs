@@ -0,0 +1,162 @@
+//! Generalized singular value decomposition.
+//!
+//! For m-by-n A and p-by-n B the decomposition is A = U*diag(alpha)*X^T and
+//! B = V*diag(beta)*X^T with orthonormal nonzero columns in U and V, nonsingular X if [A; B] has
+//! full column rank, and alpha^2 + beta^2 = 1. Generalized singular values are ratios alpha/beta.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use super::{OpCodes, CopyOps, Error, blas, lapack};
+use super::dense::{Matrix};
+use super::vec::{Vector};
+use super::scalar::{Scalar};
+
+/// Generalized singular value decomposition of matrix pair (A, B).
+#[derive(Debug, Clone)]
+pub struct GSVD<T: Scalar = f64> {
+    u: Matrix<T>,
+    v: Matrix<T>,
+    x: Matrix<T>,
+    alpha: Vector<T>,
+    beta: Vector<T>
+}
+
+impl<T: Scalar> GSVD<T> {
+    /// Compute GSVD of m-by-n A and p-by-n B with m + p >= n.
+    ///
+    /// Stacked matrix [A; B] = Q*R is factorized with QR, followed by CS decomposition of
+    /// Q = [Q1; Q2]. SVD Q1 = U*C*W^T gives alpha and pairs with alpha <= 1/sqrt(2) take
+    /// beta = sqrt(1 - alpha^2) and V from normalized columns of Q2*W. For the remaining pairs
+    /// beta is computed from SVD of Q2*W restricted to their columns and alpha = sqrt(1 - beta^2).
+    /// Then X = R^T*W. Pairs are ordered with alpha decreasing.
+    ///
+    /// If m < n the last n - m pairs are (0, 1) and if p < n the first n - p pairs are (1, 0).
+    /// Columns of U paired with zero alpha and columns of V paired with zero beta are zero.
+    pub fn new(A: &Matrix<T>, B: &Matrix<T>) -> Result<GSVD<T>, i32> {
+        let (m, n) = A.size();
+        let (p, bcols) = B.size();
+        if bcols != n || m + p < n {
+            return Err(Error::ESize as i32);
+        }
+        // [A; B] = Q*R
+        let mut Q = Matrix::new(m + p, n);
+        A.copy_to(&mut Q.row_range_mut(0, m), CopyOps::All);
+        B.copy_to(&mut Q.row_range_mut(m, m + p), CopyOps::All);
+        let mut tau = Vector::new(n);
+        lapack::qrfactor(&mut Q, &mut tau)?;
+        let mut R = Matrix::new(n, n);
+        for (i, j, v) in Q.row_range(0, n).iter() {
+            if i <= j {
+                R.set(i, j, v);
+            }
+        }
+        lapack::qrbuild(&mut Q, &tau, n)?;
+
+        // Q1 = U*C*W^T, Q1 padded with zero rows if m < n
+        let mut Q1 = Matrix::new(m.max(n), n);
+        Q.row_range(0, m).copy_to(&mut Q1.row_range_mut(0, m), CopyOps::All);
+        let mut alpha = Vector::new(n);
+        let mut Uc = Matrix::new(m.max(n), n);
+        let mut Wt = Matrix::new(n, n);
+        lapack::svd(&mut alpha, &mut Uc, &mut Wt, &mut Q1, Some(OpCodes::WANTU|OpCodes::WANTV))?;
+        let mut W = Matrix::new(n, n);
+        for (i, j, v) in Wt.iter() {
+            W.set(j, i, v);
+        }
+
+        let mut U = Matrix::new(m, n);
+        let mut V = Matrix::new(p, n);
+        let mut beta = Vector::new(n);
+        let half = T::from_f64(0.5f64.sqrt());
+        let k = (0..n).take_while(|&j| alpha.get(j) > half).count() as u32;
+
+        // Small alpha: U from SVD of Q1 and V from normalized columns of Q2*W
+        let mut QW = Matrix::new(p, n);
+        blas::mult(T::zero(), &mut QW, T::one(), &Q.row_range(m, m + p), &W, None)?;
+        for j in k..n {
+            if j >= m {
+                alpha.set(j, T::zero());
+            } else {
+                Uc.submatrix(0, j, m, 1).copy_to(&mut U.submatrix_mut(0, j, m, 1), CopyOps::All);
+            }
+            let a = alpha.get(j);
+            let b = (T::one() - a * a).sqrt();
+            beta.set(j, b);
+            QW.column(j).copy_to(&mut V.column_mut(j));
+            blas::scale(&mut V.column_mut(j), T::one() / b)?;
+        }
+
+        // Large alpha: Q2*W2 = V2*S*Z^T, Q2 padded with zero rows if p < k
+        if k > 0 {
+            let mut Q2 = Matrix::new(p.max(k), k);
+            QW.column_range(0, k).copy_to(&mut Q2.row_range_mut(0, p), CopyOps::All);
+            let mut s = Vector::new(k);
+            let mut Vs = Matrix::new(p.max(k), k);
+            let mut Zt = Matrix::new(k, k);
+            lapack::svd(&mut s, &mut Vs, &mut Zt, &mut Q2, Some(OpCodes::WANTU|OpCodes::WANTV))?;
+            // W2 = W2*Z, reversed to order alpha decreasing
+            let mut W2 = Matrix::new(n, k);
+            blas::mult(T::zero(), &mut W2, T::one(), &W.column_range(0, k), &Zt, Some(OpCodes::TRANSB))?;
+            let mut QW2 = Matrix::new(m, k);
+            blas::mult(T::zero(), &mut QW2, T::one(), &Q.row_range(0, m), &W2, None)?;
+            // rank of Q2 is at most p, smallest n - p values are zero
+            let nz = k - k.min(n.saturating_sub(p));
+            for j in 0..k {
+                let i = k - 1 - j;
+                W2.column(i).copy_to(&mut W.column_mut(j));
+                let b = if i >= nz { T::zero() } else { s.get(i) };
+                let a = (T::one() - b * b).sqrt();
+                alpha.set(j, a);
+                beta.set(j, b);
+                QW2.column(i).copy_to(&mut U.column_mut(j));
+                blas::scale(&mut U.column_mut(j), T::one() / a)?;
+                if i < nz {
+                    Vs.submatrix(0, i, p, 1).copy_to(&mut V.submatrix_mut(0, j, p, 1), CopyOps::All);
+                }
+            }
+        }
+
+        // X = R^T*W
+        let mut X = Matrix::new(n, n);
+        blas::mult(T::zero(), &mut X, T::one(), &R, &W, Some(OpCodes::TRANSA))?;
+        Ok(GSVD { u: U, v: V, x: X, alpha, beta })
+    }
+
+    /// Left m-by-n factor of A, columns orthonormal where alpha is nonzero.
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// Left p-by-n factor of B, columns orthonormal where beta is nonzero.
+    pub fn v(&self) -> &Matrix<T> {
+        &self.v
+    }
+
+    /// Common n-by-n right factor X.
+    pub fn x(&self) -> &Matrix<T> {
+        &self.x
+    }
+
+    /// Diagonal of A factor.
+    pub fn alpha(&self) -> &Vector<T> {
+        &self.alpha
+    }
+
+    /// Diagonal of B factor.
+    pub fn beta(&self) -> &Vector<T> {
+        &self.beta
+    }
+
+    /// Generalized singular values alpha/beta, infinite where beta is zero.
+    pub fn values(&self) -> Vector<T> {
+        let n = self.alpha.size();
+        let mut sv = Vector::new(n);
+        for k in 0..n {
+            let b = self.beta.get(k);
+            let v = if b == T::zero() { T::from_f64(f64::INFINITY) } else { self.alpha.get(k) / b };
+            sv.set(k, v);
+        }
+        sv
+    }
+}
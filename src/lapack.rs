@@ -4,15 +4,16 @@
 
 use libarmasd_sys as ffi;
 
-use super::{OpCodes};
-use super::dense::{Matrix};
-use super::vec::{Vector};
+use super::{OpCodes, CopyOps, Error, blas};
+use super::dense::{Matrix, AsMatrix, AsMatrixMut};
+use super::vec::{Vector, AsVector, AsVectorMut};
+use super::scalar::{Scalar};
 use super::pivot::*;
 
 /// Compute QR factorization of matrix.
-pub fn qrfactor(A: &mut Matrix, tau: &mut Vector) -> Result<(), i32> {
+pub fn qrfactor<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &mut impl AsVectorMut<T>) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_qrfactor(A.as_mut_ptr(), tau.as_mut_ptr(), ffi::armas_conf_default()) {
+        match T::armas_qrfactor(A.as_mut_ptr(), tau.as_mut_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -20,9 +21,9 @@ pub fn qrfactor(A: &mut Matrix, tau: &mut Vector) -> Result<(), i32> {
 }
 
 /// Build the Q matrix of QR factorization.
-pub fn qrbuild(A: &mut Matrix, tau: &Vector, k: u32) -> Result<(), i32> {
+pub fn qrbuild<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &impl AsVector<T>, k: u32) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_qrbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, ffi::armas_conf_default()) {
+        match T::armas_qrbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -30,20 +31,20 @@ pub fn qrbuild(A: &mut Matrix, tau: &Vector, k: u32) -> Result<(), i32> {
 }
 
 /// Multiply matrix with Q matrix of QR factorization.
-pub fn qrmult(C: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn qrmult<T: Scalar>(C: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, tau: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_qrmult(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_qrmult(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
     }
 }
 
-pub fn qrsolve(C: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn qrsolve<T: Scalar>(C: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, tau: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_qrsolve(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_qrsolve(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -51,9 +52,9 @@ pub fn qrsolve(C: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -
 }
 
 /// Compute LQ factorization of matrix.
-pub fn lqfactor(A: &mut Matrix, tau: &mut Vector) -> Result<(), i32> {
+pub fn lqfactor<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &mut impl AsVectorMut<T>) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_lqfactor(A.as_mut_ptr(), tau.as_mut_ptr(), ffi::armas_conf_default()) {
+        match T::armas_lqfactor(A.as_mut_ptr(), tau.as_mut_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -61,9 +62,9 @@ pub fn lqfactor(A: &mut Matrix, tau: &mut Vector) -> Result<(), i32> {
 }
 
 /// Build the Q matrix of LQ factorization.
-pub fn lqbuild(A: &mut Matrix, tau: &Vector, k: u32) -> Result<(), i32> {
+pub fn lqbuild<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &impl AsVector<T>, k: u32) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_lqbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, ffi::armas_conf_default()) {
+        match T::armas_lqbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -71,20 +72,20 @@ pub fn lqbuild(A: &mut Matrix, tau: &Vector, k: u32) -> Result<(), i32> {
 }
 
 /// Multiply matrix with Q matrix of LQ factorization.
-pub fn lqmult(C: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn lqmult<T: Scalar>(C: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, tau: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_lqmult(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_lqmult(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
     }
 }
 
-pub fn lqsolve(C: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn lqsolve<T: Scalar>(C: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, tau: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_lqsolve(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_lqsolve(C.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -92,10 +93,10 @@ pub fn lqsolve(C: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -
 }
 
 /// Compute LDL^T factorization of symmetric matrix.
-pub fn ldlfactor(A: &mut Matrix, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn ldlfactor<T: Scalar>(A: &mut impl AsMatrixMut<T>, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_ldlfactor(A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_ldlfactor(A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -103,10 +104,10 @@ pub fn ldlfactor(A: &mut Matrix, pivot: &mut Pivot, ops: Option<OpCodes>) -> Res
 }
 
 /// Solve X = A^{-1}*B with LDL^T factorized symmetric matrix A.
-pub fn ldlsolve(B: &mut Matrix, A: &Matrix, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn ldlsolve<T: Scalar>(B: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_ldlsolve(B.as_mut_ptr(), A.as_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_ldlsolve(B.as_mut_ptr(), A.as_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -114,10 +115,10 @@ pub fn ldlsolve(B: &mut Matrix, A: &Matrix, pivot: &Pivot, ops: Option<OpCodes>)
 }
 
 /// Inverse LDL^T factorized matrix.
-pub fn ldlinverse(A: &mut Matrix, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn ldlinverse<T: Scalar>(A: &mut impl AsMatrixMut<T>, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_ldlinverse(A.as_mut_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_ldlinverse(A.as_mut_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -125,10 +126,10 @@ pub fn ldlinverse(A: &mut Matrix, pivot: &Pivot, ops: Option<OpCodes>) -> Result
 }
 
 /// Compute Bunch-Kauffman factorization of symmetric matrix.
-pub fn bkfactor(A: &mut Matrix, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn bkfactor<T: Scalar>(A: &mut impl AsMatrixMut<T>, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_ldlfactor(A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_ldlfactor(A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -136,10 +137,10 @@ pub fn bkfactor(A: &mut Matrix, pivot: &mut Pivot, ops: Option<OpCodes>) -> Resu
 }
 
 /// Solve X = A^{-1}*B with LDL factorized symmetric matrix A.
-pub fn bksolve(B: &mut Matrix, A: &Matrix, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn bksolve<T: Scalar>(B: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_ldlsolve(B.as_mut_ptr(), A.as_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_ldlsolve(B.as_mut_ptr(), A.as_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -147,9 +148,9 @@ pub fn bksolve(B: &mut Matrix, A: &Matrix, pivot: &Pivot, ops: Option<OpCodes>)
 }
 
 /// Compute LU factorization of  matrix.
-pub fn lufactor(A: &mut Matrix, pivot: &mut Pivot) -> Result<(), i32> {
+pub fn lufactor<T: Scalar>(A: &mut impl AsMatrixMut<T>, pivot: &mut Pivot) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_lufactor(A.as_mut_ptr(), pivot.as_mut_ptr(), ffi::armas_conf_default()) {
+        match T::armas_lufactor(A.as_mut_ptr(), pivot.as_mut_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -157,10 +158,10 @@ pub fn lufactor(A: &mut Matrix, pivot: &mut Pivot) -> Result<(), i32> {
 }
 
 /// Solve X = A^{-1}*B with LU factorized  matrix A.
-pub fn lusolve(B: &mut Matrix, A: &mut Matrix, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn lusolve<T: Scalar>(B: &mut impl AsMatrixMut<T>, A: &mut impl AsMatrixMut<T>, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_lusolve(B.as_mut_ptr(), A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_lusolve(B.as_mut_ptr(), A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -168,9 +169,9 @@ pub fn lusolve(B: &mut Matrix, A: &mut Matrix, pivot: &mut Pivot, ops: Option<Op
 }
 
 /// Inverse LU factorized matrix.
-pub fn luinverse(A: &mut Matrix, pivot: &Pivot) -> Result<(), i32> {
+pub fn luinverse<T: Scalar>(A: &mut impl AsMatrixMut<T>, pivot: &Pivot) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_luinverse(A.as_mut_ptr(), pivot.as_ptr(), ffi::armas_conf_default()) {
+        match T::armas_luinverse(A.as_mut_ptr(), pivot.as_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -178,10 +179,10 @@ pub fn luinverse(A: &mut Matrix, pivot: &Pivot) -> Result<(), i32> {
 }
 
 /// Compute Cholesky factorization of  matrix.
-pub fn cholfactor(A: &mut Matrix, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn cholfactor<T: Scalar>(A: &mut impl AsMatrixMut<T>, pivot: &mut Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_cholfactor(A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_cholfactor(A.as_mut_ptr(), pivot.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -189,10 +190,10 @@ pub fn cholfactor(A: &mut Matrix, pivot: &mut Pivot, ops: Option<OpCodes>) -> Re
 }
 
 /// Solve X = A^{-1}*B with LU factorized  matrix A.
-pub fn cholsolve(B: &mut Matrix, A: &Matrix, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn cholsolve<T: Scalar>(B: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, pivot: &Pivot, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_cholsolve(B.as_mut_ptr(), A.as_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_cholsolve(B.as_mut_ptr(), A.as_ptr(), pivot.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -200,10 +201,10 @@ pub fn cholsolve(B: &mut Matrix, A: &Matrix, pivot: &Pivot, ops: Option<OpCodes>
 }
 
 /// Compute unpivoted Cholesky factorization of matrix.
-pub fn cholesky(A: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn cholesky<T: Scalar>(A: &mut impl AsMatrixMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_cholesky(A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_cholesky(A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -211,21 +212,70 @@ pub fn cholesky(A: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
 }
 
 /// Rank update unpivoted Cholesky factorization of matrix.
-pub fn cholupdate(A: &mut Matrix, x: &mut Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn cholupdate<T: Scalar>(A: &mut impl AsMatrixMut<T>, x: &mut impl AsVectorMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_cholupdate(A.as_mut_ptr(), x.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_cholupdate(A.as_mut_ptr(), x.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
     }
 }
 
+/// Downdate unpivoted Cholesky factorization of matrix A = L*L^T to A - x*x^T using hyperbolic
+/// rotations. Vector x is overwritten. Fails with ENegative and leaves A unchanged if the
+/// downdated matrix is not positive definite.
+pub fn choldowndate<T: Scalar>(A: &mut impl AsMatrixMut<T>, x: &mut impl AsVectorMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
+    let (n, cols) = A.size();
+    if n != cols || x.size() != n {
+        return Err(Error::ESize as i32);
+    }
+    let upper = ops.is_some_and(|o| o.contains(OpCodes::UPPER));
+    let index = |k: u32, i: u32| if upper { (k, i) } else { (i, k) };
+    let mut L = Matrix::copy_from(A);
+    let mut w = Vector::copy_from(x);
+    for k in 0..n {
+        let a = L.get(k, k);
+        let xk = w.get(k);
+        let r2 = a * a - xk * xk;
+        if r2 <= T::zero() {
+            return Err(Error::ENegative as i32);
+        }
+        let r = r2.sqrt();
+        let c = r / a;
+        let s = xk / a;
+        L.set(k, k, r);
+        for i in k + 1..n {
+            let (row, col) = index(k, i);
+            let l = (L.get(row, col) - s * w.get(i)) / c;
+            L.set(row, col, l);
+            w.set(i, c * w.get(i) - s * l);
+        }
+    }
+    L.copy_to(A, CopyOps::All);
+    w.copy_to(x);
+    Ok(())
+}
+
+/// Rank-k update of unpivoted Cholesky factorization of matrix A = L*L^T to A + X*X^T.
+pub fn cholupdate_k<T: Scalar>(A: &mut impl AsMatrixMut<T>, X: &impl AsMatrix<T>, ops: Option<OpCodes>) -> Result<(), i32> {
+    let (n, cols) = A.size();
+    let (rows, _) = X.size();
+    if n != cols || rows != n {
+        return Err(Error::ESize as i32);
+    }
+    let mut W = Matrix::copy_from(X);
+    for mut x in W.columns_mut() {
+        cholupdate(A, &mut x, ops)?;
+    }
+    Ok(())
+}
+
 /// Inverse update unpivoted Cholesky factorized matrix.
-pub fn cholinverse(A: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn cholinverse<T: Scalar>(A: &mut impl AsMatrixMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_cholinverse(A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_cholinverse(A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -233,19 +283,19 @@ pub fn cholinverse(A: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
 }
 
 /// Compute Hessenberg reduction of matrix.
-pub fn hessreduce(A: &mut Matrix, tau: &mut Vector) -> Result<(), i32> {
+pub fn hessreduce<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &mut impl AsVectorMut<T>) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_hessreduce(A.as_mut_ptr(), tau.as_mut_ptr(), ffi::armas_conf_default()) {
+        match T::armas_hessreduce(A.as_mut_ptr(), tau.as_mut_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
     }
 }
 
-pub fn hessmult(B: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn hessmult<T: Scalar>(B: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, tau: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_hessmult(B.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_hessmult(B.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -253,9 +303,9 @@ pub fn hessmult(B: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>)
 }
 
 /// Compute bidiagonal reduction A = Q*B*P^T of matrix.
-pub fn bdreduce(A: &mut Matrix, tauq: &mut Vector, taup: &mut Vector) -> Result<(), i32> {
+pub fn bdreduce<T: Scalar>(A: &mut impl AsMatrixMut<T>, tauq: &mut impl AsVectorMut<T>, taup: &mut impl AsVectorMut<T>) -> Result<(), i32> {
     unsafe {
-        match ffi::armas_bdreduce(A.as_mut_ptr(), tauq.as_mut_ptr(), taup.as_mut_ptr(), ffi::armas_conf_default()) {
+        match T::armas_bdreduce(A.as_mut_ptr(), tauq.as_mut_ptr(), taup.as_mut_ptr(), ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -263,10 +313,10 @@ pub fn bdreduce(A: &mut Matrix, tauq: &mut Vector, taup: &mut Vector) -> Result<
 }
 
 ///
-pub fn bdbuild(A: &mut Matrix, tau: &Vector, k: u32, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn bdbuild<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &impl AsVector<T>, k: u32, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_bdbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, bits, ffi::armas_conf_default()) {
+        match T::armas_bdbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -274,10 +324,10 @@ pub fn bdbuild(A: &mut Matrix, tau: &Vector, k: u32, ops: Option<OpCodes>) -> Re
 }
 
 ///
-pub fn bdmult(B: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn bdmult<T: Scalar>(B: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, tau: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_bdmult(B.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_bdmult(B.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -285,10 +335,10 @@ pub fn bdmult(B: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) ->
 }
 
 /// Compute tridiagonal reduction A = Q*T*Q^T of symmetric matrix.
-pub fn trdreduce(A: &mut Matrix, tau: &mut Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn trdreduce<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &mut impl AsVectorMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_trdreduce(A.as_mut_ptr(), tau.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_trdreduce(A.as_mut_ptr(), tau.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -296,10 +346,10 @@ pub fn trdreduce(A: &mut Matrix, tau: &mut Vector, ops: Option<OpCodes>) -> Resu
 }
 
 ///
-pub fn trdbuild(A: &mut Matrix, tau: &Vector, k: u32, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn trdbuild<T: Scalar>(A: &mut impl AsMatrixMut<T>, tau: &impl AsVector<T>, k: u32, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_trdbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, bits, ffi::armas_conf_default()) {
+        match T::armas_trdbuild(A.as_mut_ptr(), tau.as_ptr(), k as i32, bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -307,20 +357,20 @@ pub fn trdbuild(A: &mut Matrix, tau: &Vector, k: u32, ops: Option<OpCodes>) -> R
 }
 
 ///
-pub fn trdmult(B: &mut Matrix, A: &Matrix, tau: &Vector, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn trdmult<T: Scalar>(B: &mut impl AsMatrixMut<T>, A: &impl AsMatrix<T>, tau: &impl AsVector<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_trdmult(B.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_trdmult(B.as_mut_ptr(), A.as_ptr(), tau.as_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
     }
 }
 
-pub fn trdeigen(d: &mut Vector, e: &mut Vector, V: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn trdeigen<T: Scalar>(d: &mut impl AsVectorMut<T>, e: &mut impl AsVectorMut<T>, V: &mut impl AsMatrixMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_trdeigen(d.as_mut_ptr(), e.as_mut_ptr(), V.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_trdeigen(d.as_mut_ptr(), e.as_mut_ptr(), V.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -328,10 +378,10 @@ pub fn trdeigen(d: &mut Vector, e: &mut Vector, V: &mut Matrix, ops: Option<OpCo
 }
 
 // Compute singular value  decomposition  B = U*S*V^T of bidiagonal matrix.
-pub fn bdsvd(d: &mut Vector, e: &mut Vector, U: &mut Matrix, V: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn bdsvd<T: Scalar>(d: &mut impl AsVectorMut<T>, e: &mut impl AsVectorMut<T>, U: &mut impl AsMatrixMut<T>, V: &mut impl AsMatrixMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_bdsvd(d.as_mut_ptr(), e.as_mut_ptr(), U.as_mut_ptr(), V.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_bdsvd(d.as_mut_ptr(), e.as_mut_ptr(), U.as_mut_ptr(), V.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
@@ -339,22 +389,177 @@ pub fn bdsvd(d: &mut Vector, e: &mut Vector, U: &mut Matrix, V: &mut Matrix, ops
 }
 
 /// Compute singular value  decomposition  A = U*S*V^T of matrix.
-pub fn svd(s: &mut Vector, U: &mut Matrix, V: &mut Matrix, A: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn svd<T: Scalar>(s: &mut impl AsVectorMut<T>, U: &mut impl AsMatrixMut<T>, V: &mut impl AsMatrixMut<T>, A: &mut impl AsMatrixMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_svd(s.as_mut_ptr(), U.as_mut_ptr(), V.as_mut_ptr(), A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_svd(s.as_mut_ptr(), U.as_mut_ptr(), V.as_mut_ptr(), A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
     }
 }
 
-pub fn eigen_sym(d: &mut Vector, A: &mut Matrix, ops: Option<OpCodes>) -> Result<(), i32> {
+pub fn eigen_sym<T: Scalar>(d: &mut impl AsVectorMut<T>, A: &mut impl AsMatrixMut<T>, ops: Option<OpCodes>) -> Result<(), i32> {
     unsafe {
         let bits = ops.unwrap_or(OpCodes::NOTRANS).bits();
-        match ffi::armas_eigen_sym(d.as_mut_ptr(), A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
+        match T::armas_eigen_sym(d.as_mut_ptr(), A.as_mut_ptr(), bits, ffi::armas_conf_default()) {
             0 => Ok(()),
             x => Err(-x)
         }
     }
 }
+
+/// Type of generalized symmetric-definite eigenproblem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneralizedProblem {
+    /// A*x = lambda*B*x
+    AxBx = 1,
+    /// A*B*x = lambda*x
+    ABx = 2,
+    /// B*A*x = lambda*x
+    BAx = 3,
+}
+
+/// Compute eigenvalues and eigenvectors of generalized symmetric-definite eigenproblem with
+/// symmetric A and symmetric positive definite B. Matrix elements are read from lower (upper)
+/// triangular part as selected by OpCodes::LOWER (UPPER).
+///
+/// Problem is reduced to standard form with Cholesky factorization B = L*L^T. Eigenvectors
+/// are returned as columns of matrix X and are normalized as X^T*B*X = I for problems AxBx and
+/// ABx and as X^T*B^-1*X = I for problem BAx. Fails with ENegative if B is not positive definite.
+pub fn eigen_sym_generalized<T: Scalar>(A: &Matrix<T>, B: &Matrix<T>, problem: GeneralizedProblem, ops: Option<OpCodes>) -> Result<(Vector<T>, Matrix<T>), i32> {
+    let (n, cols) = A.size();
+    if n != cols || B.size() != (n, n) {
+        return Err(Error::ESize as i32);
+    }
+    let upper = ops.is_some_and(|o| o.contains(OpCodes::UPPER));
+    let mut L = symmetric_full(B, upper);
+    cholesky(&mut L, Some(OpCodes::LOWER))?;
+    // Reduce to standard problem C*y = lambda*y
+    let mut C = symmetric_full(A, upper);
+    match problem {
+        GeneralizedProblem::AxBx => {
+            blas::solve_trm(&mut C, T::one(), &L, Some(OpCodes::LOWER|OpCodes::LEFT))?;
+            blas::solve_trm(&mut C, T::one(), &L, Some(OpCodes::LOWER|OpCodes::TRANS|OpCodes::RIGHT))?;
+        },
+        GeneralizedProblem::ABx | GeneralizedProblem::BAx => {
+            blas::mult_trm(&mut C, T::one(), &L, Some(OpCodes::LOWER|OpCodes::TRANS|OpCodes::LEFT))?;
+            blas::mult_trm(&mut C, T::one(), &L, Some(OpCodes::LOWER|OpCodes::RIGHT))?;
+        }
+    }
+    let mut d = Vector::new(n);
+    eigen_sym(&mut d, &mut C, Some(OpCodes::LOWER|OpCodes::WANTV))?;
+    // Back transform eigenvectors
+    match problem {
+        GeneralizedProblem::AxBx | GeneralizedProblem::ABx =>
+            blas::solve_trm(&mut C, T::one(), &L, Some(OpCodes::LOWER|OpCodes::TRANS|OpCodes::LEFT))?,
+        GeneralizedProblem::BAx =>
+            blas::mult_trm(&mut C, T::one(), &L, Some(OpCodes::LOWER|OpCodes::LEFT))?,
+    }
+    Ok((d, C))
+}
+
+// Copy symmetric matrix stored in lower (upper) triangle to full matrix.
+fn symmetric_full<T: Scalar>(A: &Matrix<T>, upper: bool) -> Matrix<T> {
+    let mut F = A.clone();
+    for (i, j, v) in A.iter() {
+        if (upper && i < j) || (!upper && i > j) {
+            F.set(j, i, v);
+        }
+    }
+    F
+}
+
+/// Compute determinant of square matrix with LU factorization.
+pub fn det<T: Scalar>(A: &Matrix<T>) -> Result<T, i32> {
+    let (sign, logabs) = slogdet(A)?;
+    Ok(sign * T::from_f64(logabs.to_f64().exp()))
+}
+
+/// Compute sign and natural logarithm of absolute value of determinant of square matrix
+/// with LU factorization. For singular matrix sign is zero and logarithm -inf.
+pub fn slogdet<T: Scalar>(A: &Matrix<T>) -> Result<(T, T), i32> {
+    let (rows, cols) = A.size();
+    if rows != cols {
+        return Err(Error::ESize as i32);
+    }
+    let mut LU = A.clone();
+    let mut pivot = Pivot::new(rows);
+    match lufactor(&mut LU, &mut pivot) {
+        Ok(()) => {},
+        Err(x) if x == Error::ESingular as i32 => return Ok((T::zero(), T::from_f64(f64::NEG_INFINITY))),
+        Err(x) => return Err(x)
+    }
+    let (sign, logabs) = slogdet_diag(LU.diagonal(0).iter().map(|(_, v)| v));
+    Ok((T::from_f64(sign * pivot.sign() as f64), logabs))
+}
+
+/// Compute natural logarithm of determinant of symmetric positive definite matrix with
+/// Cholesky factorization. Matrix elements are read from lower (upper) triangular part as
+/// selected by OpCodes::LOWER (UPPER). Fails with ENegative if matrix is not positive definite.
+pub fn logdet_spd<T: Scalar>(A: &Matrix<T>, ops: Option<OpCodes>) -> Result<T, i32> {
+    let (rows, cols) = A.size();
+    if rows != cols {
+        return Err(Error::ESize as i32);
+    }
+    let mut C = A.clone();
+    cholesky(&mut C, ops)?;
+    Ok(logdet_cholesky(&C))
+}
+
+// Natural logarithm of determinant of matrix with Cholesky factor L (or U), 2*sum(ln|l_kk|).
+pub(crate) fn logdet_cholesky<T: Scalar>(L: &Matrix<T>) -> T {
+    let (_, logabs) = slogdet_diag(L.diagonal(0).iter().map(|(_, v)| v));
+    T::from_f64(2.0) * logabs
+}
+
+/// Compute sign and natural logarithm of absolute value of determinant of symmetric
+/// matrix with Bunch-Kaufman LDL^T factorization. Determinant is product of determinants
+/// of the 1x1 and 2x2 diagonal blocks of D.
+pub fn slogdet_ldl<T: Scalar>(A: &Matrix<T>, ops: Option<OpCodes>) -> Result<(T, T), i32> {
+    let (rows, cols) = A.size();
+    if rows != cols {
+        return Err(Error::ESize as i32);
+    }
+    let upper = ops.is_some_and(|o| o.contains(OpCodes::UPPER));
+    let mut LD = A.clone();
+    let mut pivot = Pivot::new(rows);
+    match bkfactor(&mut LD, &mut pivot, ops) {
+        Ok(()) => {},
+        Err(x) if x == Error::ESingular as i32 => return Ok((T::zero(), T::from_f64(f64::NEG_INFINITY))),
+        Err(x) => return Err(x)
+    }
+    // Collect determinants of diagonal blocks; 2x2 block is marked with negative
+    // pivot indexes at both of its rows.
+    let mut blocks = Vec::with_capacity(rows as usize);
+    let mut k = 0;
+    while k < rows {
+        if pivot.get_raw(k) < 0 && k + 1 < rows {
+            let offdiag = if upper { LD.get(k, k + 1) } else { LD.get(k + 1, k) };
+            blocks.push(LD.get(k, k) * LD.get(k + 1, k + 1) - offdiag * offdiag);
+            k += 2;
+        } else {
+            blocks.push(LD.get(k, k));
+            k += 1;
+        }
+    }
+    let (sign, logabs) = slogdet_diag(blocks.into_iter());
+    Ok((T::from_f64(sign), logabs))
+}
+
+// Sign and logarithm of absolute value of product of elements.
+fn slogdet_diag<T: Scalar, I: Iterator<Item = T>>(elems: I) -> (f64, T) {
+    let mut sign = 1.0;
+    let mut logabs = 0.0;
+    for v in elems {
+        let v = v.to_f64();
+        if v == 0.0 {
+            return (0.0, T::from_f64(f64::NEG_INFINITY));
+        }
+        if v < 0.0 {
+            sign = -sign;
+        }
+        logabs += v.abs().ln();
+    }
+    (sign, T::from_f64(logabs))
+}
@@ -1,6 +1,7 @@
 #[macro_use]
 extern crate bitflags;
 
+use std::fmt;
 use libarmasd_sys as ffi;
 
 bitflags! {
@@ -53,6 +54,42 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Runtime options of the armas library.
+    pub struct ConfFlags: i32 {
+        /// Naive, unblocked algorithms
+        const NAIVE = ffi::ARMAS_ONAIVE as i32;
+        /// Kahan summation
+        const KAHAN = ffi::ARMAS_OKAHAN as i32;
+        /// Pairwise summation
+        const PAIRWISE = ffi::ARMAS_OPAIRWISE as i32;
+        /// Recursive algorithms
+        const RECURSIVE = ffi::ARMAS_ORECURSIVE as i32;
+        /// Recursive BLAS algorithms
+        const BLAS_RECURSIVE = ffi::ARMAS_OBLAS_RECURSIVE as i32;
+        /// Blocked BLAS algorithms
+        const BLAS_BLOCKED = ffi::ARMAS_OBLAS_BLOCKED as i32;
+        /// Tiled BLAS algorithms
+        const BLAS_TILED = ffi::ARMAS_OBLAS_TILED as i32;
+        /// Round robin scheduling of accelerator workers
+        const SCHED_ROUNDROBIN = ffi::ARMAS_OSCHED_ROUNDROBIN as i32;
+        /// Random scheduling of accelerator workers
+        const SCHED_RANDOM = ffi::ARMAS_OSCHED_RANDOM as i32;
+        /// Two-choice scheduling of accelerator workers
+        const SCHED_TWO = ffi::ARMAS_OSCHED_TWO as i32;
+        /// Golub-Kahan bidiagonal SVD
+        const BSVD_GOLUB = ffi::ARMAS_OBSVD_GOLUB as i32;
+        /// Demmel-Kahan bidiagonal SVD
+        const BSVD_DEMMEL = ffi::ARMAS_OBSVD_DEMMEL as i32;
+        /// Absolute tolerance in eigenvalue computations
+        const ABSTOL = ffi::ARMAS_OABSTOL as i32;
+        /// Extended precision BLAS functions (requires ext-precision feature)
+        const EXTPREC = ffi::ARMAS_OEXTPREC as i32;
+        /// Request non-negative result
+        const NONNEG = ffi::ARMAS_ONONNEG as i32;
+    }
+}
+
 pub enum CopyOps {
     All = 0,
     Lower = 0x1,
@@ -83,6 +120,7 @@ pub enum PivotOps {
     Lower = 0x10,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error {
     ENone = 0,
     /// Operand size mismatch
@@ -113,11 +151,74 @@ pub enum Error {
     ESvdEigen = 13,
 }
 
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self {
+            Error::ENone => "no error",
+            Error::ESize => "operand size mismatch",
+            Error::ENeedVector => "vector operand required",
+            Error::EInval => "invalid parameter",
+            Error::EImp => "not implemented",
+            Error::EWork => "workspace too small",
+            Error::ESingular => "singular matrix",
+            Error::ENegative => "negative value on diagonal",
+            Error::EMemory => "memory allocation failed",
+            Error::EConverge => "algorithm does not converge",
+            Error::ESvdFact => "svd factorization failed",
+            Error::ESvdLeft => "svd left eigenvector error",
+            Error::ESvdRight => "svd right eigenvector error",
+            Error::ESvdEigen => "svd bidiagonal eigenvalue error",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Map error code returned by BLAS and LAPACK functions to error. Unknown codes map
+/// to EInval.
+impl From<i32> for Error {
+    fn from(code: i32) -> Error {
+        match code {
+            0 => Error::ENone,
+            1 => Error::ESize,
+            2 => Error::ENeedVector,
+            3 => Error::EInval,
+            4 => Error::EImp,
+            5 => Error::EWork,
+            6 => Error::ESingular,
+            7 => Error::ENegative,
+            8 => Error::EMemory,
+            9 => Error::EConverge,
+            10 => Error::ESvdFact,
+            11 => Error::ESvdLeft,
+            12 => Error::ESvdRight,
+            13 => Error::ESvdEigen,
+            _ => Error::EInval
+        }
+    }
+}
+
+pub mod conf;
+pub mod scalar;
 pub mod vec;
 pub mod dense;
+pub mod partition;
+pub mod structured;
+pub mod banded;
 pub mod pivot;
 pub mod blas;
 pub mod lapack;
+pub mod lstsq;
+pub mod qr;
+pub mod gsvd;
+pub mod stats;
+pub mod gallery;
+pub mod print_options;
+#[cfg(feature = "random")]
+pub mod random;
 
 mod tests;
 
+pub use scalar::Scalar;
+
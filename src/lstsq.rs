@@ -0,0 +1,270 @@
+//! Linear least squares problems.
+//!
+//! Solve min ||B - A*X|| for each column of B with QR (LQ for underdetermined systems) or
+//! SVD factorization of A. Inputs are not modified.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use super::{OpCodes, CopyOps, Error, blas, lapack};
+use super::dense::{Matrix, MatrixView};
+use super::vec::{Vector};
+use super::scalar::{Scalar};
+
+/// Solution of least squares problem.
+#[derive(Debug, Clone)]
+pub struct Solution<T: Scalar = f64> {
+    /// Solution, n-by-k matrix for m-by-n A and m-by-k B.
+    pub x: Matrix<T>,
+    /// Residual sum of squares ||b - A*x||^2 for each column of B.
+    pub rss: Vector<T>,
+    /// Rank of A, number of singular values above cutoff in SVD based solution.
+    pub rank: u32,
+    /// Unscaled coefficient covariance (A^T*A)^{-1} if A has full column rank. Multiply with
+    /// residual variance rss/(m - n) to get covariance of coefficients.
+    pub covariance: Option<Matrix<T>>
+}
+
+/// Solve least squares problem with QR factorization of A for overdetermined and minimum
+/// norm problem with LQ factorization of A for underdetermined systems. A is assumed to
+/// have full rank.
+pub fn solve_qr<T: Scalar>(A: &Matrix<T>, B: &Matrix<T>) -> Result<Solution<T>, i32> {
+    let (m, n) = A.size();
+    let (brows, k) = B.size();
+    if brows != m {
+        return Err(Error::ESize as i32);
+    }
+    let mut QR = A.clone();
+    let mut tau = Vector::new(m.min(n));
+    if m >= n {
+        lapack::qrfactor(&mut QR, &mut tau)?;
+        let mut X = B.clone();
+        lapack::qrsolve(&mut X, &QR, &tau, None)?;
+        // Rows n..m hold Q^T*B for the part not in range of A.
+        let rss = column_sumsq(&X.row_range(n, m));
+        let covariance = Some(r_covariance(&QR)?);
+        Ok(Solution { x: X.row_range(0, n).to_matrix(), rss, rank: n, covariance })
+    } else {
+        lapack::lqfactor(&mut QR, &mut tau)?;
+        let mut X = Matrix::new(n, k);
+        B.copy_to(&mut X.row_range_mut(0, m), CopyOps::All);
+        lapack::lqsolve(&mut X, &QR, &tau, None)?;
+        Ok(Solution { x: X, rss: Vector::new(k), rank: m, covariance: None })
+    }
+}
+
+/// Solve minimum norm least squares problem with SVD of A. Singular values smaller than
+/// rcond times the largest singular value are treated as zero. Default rcond is machine
+/// epsilon times max(m, n).
+pub fn solve_svd<T: Scalar>(A: &Matrix<T>, B: &Matrix<T>, rcond: Option<T>) -> Result<Solution<T>, i32> {
+    let (m, n) = A.size();
+    let (brows, k) = B.size();
+    if brows != m {
+        return Err(Error::ESize as i32);
+    }
+    let K = m.min(n);
+    let mut W = A.clone();
+    let mut s = Vector::new(K);
+    let mut U = Matrix::new(m, K);
+    let mut Vt = Matrix::new(K, n);
+    lapack::svd(&mut s, &mut U, &mut Vt, &mut W, Some(OpCodes::WANTU|OpCodes::WANTV))?;
+
+    let rcond = rcond.unwrap_or_else(|| T::EPSILON * T::from_f64(m.max(n) as f64));
+    let cutoff = if K > 0 { rcond * s.get(0) } else { T::zero() };
+    let rank = s.iter().filter(|&(_, v)| v > cutoff).count() as u32;
+    let sr = Vector::new_from(s.iter().take(rank as usize).map(|(_, v)| v).collect());
+    let Vr = Vt.row_range(0, rank);
+
+    // X = V_r * diag(s_r)^{-1} * U_r^T * B
+    let mut UtB = Matrix::new(rank, k);
+    blas::mult(T::zero(), &mut UtB, T::one(), &U.column_range(0, rank), B, Some(OpCodes::TRANSA))?;
+    blas::solve_diag(&mut UtB, T::one(), &sr, Some(OpCodes::LEFT))?;
+    let mut X = Matrix::new(n, k);
+    blas::mult(T::zero(), &mut X, T::one(), &Vr, &UtB, Some(OpCodes::TRANSA))?;
+
+    let covariance = if rank == n {
+        // V * diag(s)^{-2} * V^T
+        let mut SV = Vr.to_matrix();
+        blas::solve_diag(&mut SV, T::one(), &sr, Some(OpCodes::LEFT))?;
+        blas::solve_diag(&mut SV, T::one(), &sr, Some(OpCodes::LEFT))?;
+        let mut C = Matrix::new(n, n);
+        blas::mult(T::zero(), &mut C, T::one(), &Vr, &SV, Some(OpCodes::TRANSA))?;
+        Some(C)
+    } else {
+        None
+    };
+    let rss = residual_sumsq(A, B, &X)?;
+    Ok(Solution { x: X, rss, rank, covariance })
+}
+
+/// Solve weighted least squares problem min sum_i w_i*(b_i - a_i*x)^2 where a_i is i'th
+/// row of A. Weights must be non-negative. Residual sum of squares is weighted.
+pub fn weighted<T: Scalar>(A: &Matrix<T>, B: &Matrix<T>, w: &Vector<T>) -> Result<Solution<T>, i32> {
+    let (m, _) = A.size();
+    if w.size() != m {
+        return Err(Error::ESize as i32);
+    }
+    if w.iter().any(|(_, v)| v < T::zero()) {
+        return Err(Error::EInval as i32);
+    }
+    let sw = Vector::new_from(w.iter().map(|(_, v)| v.sqrt()).collect());
+    let mut Aw = A.clone();
+    let mut Bw = B.clone();
+    blas::mult_diag(&mut Aw, T::one(), &sw, Some(OpCodes::LEFT))?;
+    blas::mult_diag(&mut Bw, T::one(), &sw, Some(OpCodes::LEFT))?;
+    solve_qr(&Aw, &Bw)
+}
+
+/// Solve ridge regression (Tikhonov regularization) problem min ||B - A*X||^2 + lambda*||X||^2
+/// as least squares problem with augmented matrix [A; sqrt(lambda)*I]. Residual sum of
+/// squares is computed for the unregularized problem and covariance is (A^T*A + lambda*I)^{-1}.
+pub fn ridge<T: Scalar>(A: &Matrix<T>, B: &Matrix<T>, lambda: T) -> Result<Solution<T>, i32> {
+    let (m, n) = A.size();
+    let (brows, k) = B.size();
+    if brows != m {
+        return Err(Error::ESize as i32);
+    }
+    if lambda < T::zero() {
+        return Err(Error::EInval as i32);
+    }
+    let mut Aa = Matrix::new(m + n, n);
+    A.copy_to(&mut Aa.row_range_mut(0, m), CopyOps::All);
+    let mut d = Aa.row_range_mut(m, m + n);
+    for j in 0..n {
+        d.set(j, j, lambda.sqrt());
+    }
+    let mut Ba = Matrix::new(m + n, k);
+    B.copy_to(&mut Ba.row_range_mut(0, m), CopyOps::All);
+
+    let mut sol = solve_qr(&Aa, &Ba)?;
+    sol.rss = residual_sumsq(A, B, &sol.x)?;
+    sol.rank = n.min(m);
+    Ok(sol)
+}
+
+/// Solve equality constrained least squares problem min ||C - A*X|| subject to B*X = D
+/// for each column of C and D. A is m-by-n and B is p-by-n matrix with p <= n <= m+p.
+///
+/// Problem is solved with generalized RQ factorization: B = L*Q with LQ factorization and
+/// QR factorization of A*Q^T. B is assumed to have full row rank and [A; B] full column rank.
+/// Residual sum of squares of the least squares part is returned in the solution.
+pub fn lse<T: Scalar>(A: &Matrix<T>, C: &Matrix<T>, B: &Matrix<T>, D: &Matrix<T>) -> Result<Solution<T>, i32> {
+    let (m, n) = A.size();
+    let (p, nb) = B.size();
+    let (crows, k) = C.size();
+    if nb != n || crows != m || D.size() != (p, k) || p > n || n > m + p {
+        return Err(Error::ESize as i32);
+    }
+    // B = L*Q, Q^T*y = x and L*y1 = D
+    let mut LQ = B.clone();
+    let mut tau = Vector::new(p);
+    lapack::lqfactor(&mut LQ, &mut tau)?;
+    let mut Y = Matrix::new(n, k);
+    let mut Y1 = Y.row_range_mut(0, p);
+    D.copy_to(&mut Y1, CopyOps::All);
+    blas::solve_trm(&mut Y1, T::one(), &LQ.submatrix(0, 0, p, p), Some(OpCodes::LOWER|OpCodes::LEFT))?;
+
+    // A*x = A*Q^T*y = A1*y1 + A2*y2, solve min ||(C - A1*y1) - A2*y2|| with QR of A2
+    let mut AQ = A.clone();
+    lapack::lqmult(&mut AQ, &LQ, &tau, Some(OpCodes::RIGHT|OpCodes::TRANS))?;
+    let mut R = C.clone();
+    blas::mult(T::one(), &mut R, -T::one(), &AQ.column_range(0, p), &Y1, None)?;
+    let q = n - p;
+    if q > 0 {
+        let mut A2 = AQ.column_range(p, n).to_matrix();
+        let mut tau2 = Vector::new(q);
+        lapack::qrfactor(&mut A2, &mut tau2)?;
+        lapack::qrmult(&mut R, &A2, &tau2, Some(OpCodes::LEFT|OpCodes::TRANS))?;
+        let mut Y2 = Y.row_range_mut(p, n);
+        R.row_range(0, q).copy_to(&mut Y2, CopyOps::All);
+        blas::solve_trm(&mut Y2, T::one(), &A2.submatrix(0, 0, q, q), Some(OpCodes::UPPER|OpCodes::LEFT))?;
+    }
+    let rss = column_sumsq(&R.row_range(q, m));
+
+    let mut X = Y;
+    lapack::lqmult(&mut X, &LQ, &tau, Some(OpCodes::LEFT|OpCodes::TRANS))?;
+    Ok(Solution { x: X, rss, rank: n, covariance: None })
+}
+
+/// Solve general Gauss-Markov linear model problem min ||Y|| subject to D = A*X + B*Y for
+/// each column of D. A is n-by-m and B is n-by-p matrix with m <= n <= m+p. Returns X and Y.
+///
+/// Problem is solved with generalized QR factorization: A = Q*R with QR factorization and
+/// LQ factorization of the last n-m rows of Q^T*B. A is assumed to have full column rank and
+/// [A B] full row rank.
+pub fn glm<T: Scalar>(A: &Matrix<T>, B: &Matrix<T>, D: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), i32> {
+    let (n, m) = A.size();
+    let (nb, p) = B.size();
+    let (drows, k) = D.size();
+    if nb != n || drows != n || m > n || n > m + p {
+        return Err(Error::ESize as i32);
+    }
+    let mut QR = A.clone();
+    let mut tau = Vector::new(m);
+    lapack::qrfactor(&mut QR, &mut tau)?;
+    let mut QtD = D.clone();
+    let mut QtB = B.clone();
+    lapack::qrmult(&mut QtD, &QR, &tau, Some(OpCodes::LEFT|OpCodes::TRANS))?;
+    lapack::qrmult(&mut QtB, &QR, &tau, Some(OpCodes::LEFT|OpCodes::TRANS))?;
+
+    // Minimum norm Y for B2*Y = D2 from the last n-m rows
+    let r = n - m;
+    let mut Y = Matrix::new(p, k);
+    if r > 0 {
+        let mut LQ = QtB.row_range(m, n).to_matrix();
+        let mut tau2 = Vector::new(r);
+        lapack::lqfactor(&mut LQ, &mut tau2)?;
+        QtD.row_range(m, n).copy_to(&mut Y.row_range_mut(0, r), CopyOps::All);
+        lapack::lqsolve(&mut Y, &LQ, &tau2, None)?;
+    }
+    // R*X = D1 - B1*Y
+    let mut X = QtD.row_range(0, m).to_matrix();
+    blas::mult(T::one(), &mut X, -T::one(), &QtB.row_range(0, m), &Y, None)?;
+    blas::solve_trm(&mut X, T::one(), &QR.submatrix(0, 0, m, m), Some(OpCodes::UPPER|OpCodes::LEFT))?;
+    Ok((X, Y))
+}
+
+/// Compute unscaled coefficient covariance (A^T*A)^{-1} = (R^T*R)^{-1} of full column rank
+/// matrix A from its QR factorization.
+pub fn covariance<T: Scalar>(A: &Matrix<T>) -> Result<Matrix<T>, i32> {
+    let (m, n) = A.size();
+    if m < n {
+        return Err(Error::ESize as i32);
+    }
+    let mut QR = A.clone();
+    let mut tau = Vector::new(n);
+    lapack::qrfactor(&mut QR, &mut tau)?;
+    r_covariance(&QR)
+}
+
+// Compute (R^T*R)^{-1} from R in the upper triangle of QR factorized matrix. R is
+// the upper Cholesky factor of A^T*A.
+fn r_covariance<T: Scalar>(QR: &Matrix<T>) -> Result<Matrix<T>, i32> {
+    let (_, n) = QR.size();
+    let mut C = QR.submatrix(0, 0, n, n).to_matrix();
+    lapack::cholinverse(&mut C, Some(OpCodes::UPPER))?;
+    for j in 0..n {
+        for i in j + 1..n {
+            C.set(i, j, C.get(j, i));
+        }
+    }
+    Ok(C)
+}
+
+// Residual sum of squares of each column of B - A*X.
+fn residual_sumsq<T: Scalar>(A: &Matrix<T>, B: &Matrix<T>, X: &Matrix<T>) -> Result<Vector<T>, i32> {
+    let (m, _) = B.size();
+    let mut R = B.clone();
+    blas::mult(T::one(), &mut R, -T::one(), A, X, None)?;
+    Ok(column_sumsq(&R.row_range(0, m)))
+}
+
+fn column_sumsq<T: Scalar>(R: &MatrixView<'_, T>) -> Vector<T> {
+    let (_, k) = R.size();
+    let mut rss = Vector::new(k);
+    for (j, c) in R.columns().enumerate() {
+        let s = c.iter().fold(T::zero(), |s, (_, v)| s + v * v);
+        rss.set(j as u32, s);
+    }
+    rss
+}
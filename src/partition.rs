@@ -0,0 +1,131 @@
+//! Block partitioning of matrices for blocked and recursive algorithms.
+//!
+//! Partitions are disjoint mutable views to the partitioned matrix. Blocks borrow
+//! the matrix mutably and it is accessible again when partition is dropped.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use super::dense::{Matrix, MatrixViewMut};
+use super::scalar::{Scalar};
+
+/// Matrix partitioned to 2x2 blocks.
+///
+/// ```text
+/// A = | ATL ATR |
+///     | ABL ABR |
+/// ```
+pub struct Partition2x2<'a, T: Scalar = f64> {
+    pub tl: MatrixViewMut<'a, T>,
+    pub tr: MatrixViewMut<'a, T>,
+    pub bl: MatrixViewMut<'a, T>,
+    pub br: MatrixViewMut<'a, T>
+}
+
+/// Matrix partitioned to 3x3 blocks around the diagonal block A11.
+///
+/// ```text
+/// A = | A00 A01 A02 |
+///     | A10 A11 A12 |
+///     | A20 A21 A22 |
+/// ```
+pub struct Partition3x3<'a, T: Scalar = f64> {
+    pub a00: MatrixViewMut<'a, T>,
+    pub a01: MatrixViewMut<'a, T>,
+    pub a02: MatrixViewMut<'a, T>,
+    pub a10: MatrixViewMut<'a, T>,
+    pub a11: MatrixViewMut<'a, T>,
+    pub a12: MatrixViewMut<'a, T>,
+    pub a20: MatrixViewMut<'a, T>,
+    pub a21: MatrixViewMut<'a, T>,
+    pub a22: MatrixViewMut<'a, T>
+}
+
+/// Walk diagonal blocks of matrix from top left to bottom right corner.
+pub struct Repartition<'a, T: Scalar = f64> {
+    source: &'a mut Matrix<T>,
+    nb: u32,
+    index: u32
+}
+
+/// Partition matrix to 2x2 blocks where ATL is row-by-col block. Panics if row or col
+/// is out of bounds.
+///
+/// Blocks are views, not matrices, and their descriptors cannot be moved out of the
+/// partition:
+///
+/// ```compile_fail
+/// use armasd::{dense::Matrix, partition};
+///
+/// let mut a: Matrix = Matrix::identity(4);
+/// let mut m: Matrix = Matrix::new(2, 2);
+/// let mut p = partition::partition_2x2(&mut a, 2, 2);
+/// std::mem::swap(&mut *p.tl, &mut m);
+/// ```
+pub fn partition_2x2<T: Scalar>(A: &mut Matrix<T>, row: u32, col: u32) -> Partition2x2<'_, T> {
+    let (rows, cols) = A.size();
+    assert!(row <= rows && col <= cols, "partition out of bounds");
+    // Blocks are disjoint and borrow A mutably through the returned partition.
+    let view = |r: u32, c: u32, nr: u32, nc: u32| MatrixViewMut::new(unsafe { A.alias(r, c, nr, nc) });
+    Partition2x2 {
+        tl: view(0, 0, row, col),
+        tr: view(0, col, row, cols - col),
+        bl: view(row, 0, rows - row, col),
+        br: view(row, col, rows - row, cols - col)
+    }
+}
+
+/// Create repartitioning of matrix with diagonal blocks of size nb. Last block is
+/// smaller if nb does not divide the diagonal.
+///
+/// Blocks are borrowed from the repartitioning and must be dropped before the next
+/// block is requested.
+///
+/// ```
+/// use armasd::{dense::Matrix, partition};
+///
+/// let mut a: Matrix = Matrix::identity(64);
+/// let mut blocks = partition::repartition(&mut a, 32);
+/// while let Some(p) = blocks.next_block() {
+///     // work on p.a11, p.a21, p.a22 ...
+///     assert_eq!(p.a11.size(), (32, 32));
+/// }
+/// ```
+pub fn repartition<T: Scalar>(A: &mut Matrix<T>, nb: u32) -> Repartition<'_, T> {
+    Repartition { source: A, nb: nb.max(1), index: 0 }
+}
+
+impl<'a, T: Scalar> Repartition<'a, T> {
+    /// Get 3x3 partitioning with the next diagonal block as A11.
+    pub fn next_block(&mut self) -> Option<Partition3x3<'_, T>> {
+        let (rows, cols) = self.source.size();
+        let n = rows.min(cols);
+        if self.index >= n {
+            return None;
+        }
+        let k = self.index;
+        let kb = self.nb.min(n - k);
+        self.index += kb;
+
+        let A: &Matrix<T> = self.source;
+        let (r2, c2) = (k + kb, k + kb);
+        // Blocks are disjoint and borrow the repartitioning mutably.
+        let view = |r: u32, c: u32, nr: u32, nc: u32| MatrixViewMut::new(unsafe { A.alias(r, c, nr, nc) });
+        Some(Partition3x3 {
+            a00: view(0, 0, k, k),
+            a01: view(0, k, k, kb),
+            a02: view(0, c2, k, cols - c2),
+            a10: view(k, 0, kb, k),
+            a11: view(k, k, kb, kb),
+            a12: view(k, c2, kb, cols - c2),
+            a20: view(r2, 0, rows - r2, k),
+            a21: view(r2, k, rows - r2, kb),
+            a22: view(r2, c2, rows - r2, cols - c2)
+        })
+    }
+
+    /// Offset of the next diagonal block.
+    pub fn position(&self) -> u32 {
+        self.index
+    }
+}
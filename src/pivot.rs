@@ -1,12 +1,38 @@
 
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
 use libarmasd_sys as ffi;
 use std::mem;
-use std::convert::TryInto;
+use std::fmt;
+use std::convert::{TryFrom, TryInto};
+use serde::{Serialize, Serializer, Deserialize};
+use serde::ser::{SerializeStruct};
+
+use super::{PivotOps, Error};
+use super::dense::{Matrix};
+use super::vec::{AsVectorMut};
+use super::scalar::{Scalar};
+
+#[derive(Deserialize)]
+struct PivotShadow {
+    pivots: Vec<i32>
+}
 
+/// Pivot vector of row (column) interchanges. Element k tells that k'th row was interchanged
+/// with row get(k). Indexes are stored one based as in LAPACK, negative index marks 2x2 pivot
+/// block of Bunch-Kaufman factorization.
+#[derive(Deserialize)]
+#[serde(try_from = "PivotShadow")]
 pub struct Pivot {
     pivots: ffi::armas_pivot
 }
 
+pub struct PivotIterator<'a> {
+    source: &'a Pivot,
+    index: u32
+}
+
 impl Pivot {
 
     pub fn new(size: u32) -> Pivot {
@@ -24,6 +50,113 @@ impl Pivot {
     pub fn as_mut_ptr(&mut self) -> *mut ffi::armas_pivot {
         &mut self.pivots
     }
+
+    /// Number of pivots.
+    pub fn len(&self) -> u32 {
+        self.pivots.npivots.try_into().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get zero based index of row interchanged with k'th row. Panics if k is out of bounds.
+    pub fn get(&self, k: u32) -> u32 {
+        let index = self.get_raw(k);
+        (index.abs() - 1).try_into().unwrap_or(0)
+    }
+
+    /// Get pivot as stored by the library.
+    pub(crate) fn get_raw(&self, k: u32) -> i32 {
+        assert!(k < self.len(), "pivot index out of bounds");
+        unsafe {
+            ffi::armas_pivot_get_unsafe(&self.pivots as *const _ as *mut _, k as i32)
+        }
+    }
+
+    pub(crate) fn set_raw(&mut self, k: u32, value: i32) {
+        assert!(k < self.len(), "pivot index out of bounds");
+        unsafe {
+            ffi::armas_pivot_set_unsafe(&mut self.pivots, k as i32, value);
+        }
+    }
+
+    /// Iterate over zero based pivot indexes.
+    pub fn iter(&self) -> PivotIterator<'_> {
+        PivotIterator { source: self, index: 0 }
+    }
+
+    /// Get permutation p such that row i of the pivoted matrix is row p[i] of the
+    /// original matrix.
+    pub fn to_permutation_vec(&self) -> Vec<u32> {
+        let mut perm: Vec<u32> = (0..self.len()).collect();
+        for (k, p) in self.iter().enumerate() {
+            perm.swap(k, p as usize);
+        }
+        perm
+    }
+
+    /// Create permutation matrix P such that P*A applies row interchanges to A.
+    pub fn to_matrix<T: Scalar>(&self) -> Matrix<T> {
+        let n = self.len();
+        let mut P = Matrix::new(n, n);
+        for (i, p) in self.to_permutation_vec().into_iter().enumerate() {
+            P.set(i as u32, p, T::one());
+        }
+        P
+    }
+
+    /// Sign of the permutation, -1 for odd and 1 for even number of interchanges.
+    pub fn sign(&self) -> i32 {
+        let swaps = self.iter().enumerate().filter(|&(k, p)| k as u32 != p).count();
+        if swaps % 2 == 0 { 1 } else { -1 }
+    }
+
+    /// Apply interchanges to rows (PivotOps::Rows) or columns (PivotOps::Columns) of matrix
+    /// in PivotOps::Forward or PivotOps::Backward order.
+    pub fn apply<T: Scalar>(&self, A: &mut Matrix<T>, target: PivotOps, direction: PivotOps) -> Result<(), Error> {
+        let (rows, cols) = A.size();
+        let n = match target {
+            PivotOps::Rows => rows,
+            PivotOps::Columns => cols,
+            _ => return Err(Error::EInval)
+        };
+        let backward = match direction {
+            PivotOps::Forward => false,
+            PivotOps::Backward => true,
+            _ => return Err(Error::EInval)
+        };
+        if self.len() > n || self.iter().any(|p| p >= n) {
+            return Err(Error::ESize);
+        }
+        let mut swap = |k: u32, p: u32| {
+            let (i, j) = (k.min(p), k.max(p));
+            if i == j {
+                return;
+            }
+            let (mut first, mut second) = match target {
+                PivotOps::Rows => A.split_at_row(j),
+                _ => A.split_at_col(j)
+            };
+            let (mut x, mut y) = match target {
+                PivotOps::Rows => (first.row_mut(i), second.row_mut(0)),
+                _ => (first.column_mut(i), second.column_mut(0))
+            };
+            unsafe {
+                T::armas_swap(x.as_mut_ptr(), y.as_mut_ptr(), ffi::armas_conf_default());
+            }
+        };
+        if backward {
+            for k in (0..self.len()).rev() {
+                swap(k, self.get(k));
+            }
+        } else {
+            for (k, p) in self.iter().enumerate() {
+                swap(k as u32, p);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Drop for Pivot {
@@ -34,3 +167,61 @@ impl Drop for Pivot {
     }
 
 }
+
+impl fmt::Debug for Pivot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a> Iterator for PivotIterator<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.source.len() {
+            return None;
+        }
+        self.index += 1;
+        Some(self.source.get(self.index - 1))
+    }
+}
+
+impl<'a> IntoIterator for &'a Pivot {
+    type Item = u32;
+    type IntoIter = PivotIterator<'a>;
+
+    fn into_iter(self) -> PivotIterator<'a> {
+        self.iter()
+    }
+}
+
+/// Serialize pivots as stored by the library to preserve 2x2 block markers.
+impl Serialize for Pivot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let pivots: Vec<i32> = (0..self.len()).map(|k| self.get_raw(k)).collect();
+        let mut state = serializer.serialize_struct("Pivot", 1)?;
+        state.serialize_field("pivots", &pivots)?;
+        state.end()
+    }
+}
+
+/// Pivot entries are 1-based, negative entries mark 2x2 blocks, and must satisfy
+/// 1 <= |p| <= len.
+impl TryFrom<PivotShadow> for Pivot {
+    type Error = Error;
+
+    fn try_from(p: PivotShadow) -> Result<Self, Error> {
+        let n = p.pivots.len() as i64;
+        if p.pivots.iter().any(|&v| (v as i64).abs() < 1 || (v as i64).abs() > n) {
+            return Err(Error::EInval);
+        }
+        let mut pivot = Pivot::new(n as u32);
+        for (k, v) in p.pivots.into_iter().enumerate() {
+            pivot.set_raw(k as u32, v);
+        }
+        Ok(pivot)
+    }
+}
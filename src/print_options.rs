@@ -0,0 +1,113 @@
+//! Formatting options of matrices and vectors.
+//!
+//! Options apply to `Display` formatting. Global options are shared by all threads; local
+//! options set with `with` override them in the calling thread for the duration of a closure.
+//! Precision given in the format specifier, as in `{:.3}`, overrides the option precision.
+
+use std::cell::Cell;
+use std::fmt;
+use std::sync::RwLock;
+
+use super::scalar::{Scalar};
+
+/// Display formatting options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrintOptions {
+    /// Number of digits after decimal point.
+    pub precision: usize,
+    /// Rows (columns) are truncated if there are more than threshold of them.
+    pub threshold: u32,
+    /// Number of first and last rows (columns) shown when truncated.
+    pub edgeitems: u32,
+}
+
+impl PrintOptions {
+    /// Default options.
+    pub const DEFAULT: PrintOptions = PrintOptions { precision: 4, threshold: 12, edgeitems: 3 };
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        PrintOptions::DEFAULT
+    }
+}
+
+static GLOBAL: RwLock<PrintOptions> = RwLock::new(PrintOptions::DEFAULT);
+
+thread_local! {
+    static LOCAL: Cell<Option<PrintOptions>> = const { Cell::new(None) };
+}
+
+/// Get current options, local options of the calling thread if set.
+pub fn get() -> PrintOptions {
+    LOCAL.with(|local| local.get())
+        .unwrap_or_else(|| *GLOBAL.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Set global options.
+pub fn set(opts: PrintOptions) {
+    *GLOBAL.write().unwrap_or_else(|e| e.into_inner()) = opts;
+}
+
+// Restores previous local options also when closure panics.
+struct Restore(Option<PrintOptions>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        LOCAL.with(|local| local.set(self.0));
+    }
+}
+
+/// Run closure with local options in the calling thread.
+pub fn with<R, F: FnOnce() -> R>(opts: PrintOptions, func: F) -> R {
+    let _restore = Restore(LOCAL.with(|local| local.replace(Some(opts))));
+    func()
+}
+
+// Indexes shown of n rows (columns), None marks the truncation point.
+fn shown(n: u32, opts: &PrintOptions) -> Vec<Option<u32>> {
+    if n <= opts.threshold || 2 * opts.edgeitems >= n {
+        return (0..n).map(Some).collect();
+    }
+    let head = (0..opts.edgeitems).map(Some);
+    let tail = (n - opts.edgeitems..n).map(Some);
+    head.chain(std::iter::once(None)).chain(tail).collect()
+}
+
+/// Write rows-by-cols table of elements with aligned columns.
+pub(crate) fn write_table<T: Scalar, F>(f: &mut fmt::Formatter, rows: u32, cols: u32, elem: F) -> fmt::Result
+where
+    F: Fn(u32, u32) -> T,
+{
+    if rows == 0 || cols == 0 {
+        return f.write_str("[]");
+    }
+    let opts = get();
+    let precision = f.precision().unwrap_or(opts.precision);
+    let rowidx = shown(rows, &opts);
+    let colidx = shown(cols, &opts);
+    // formatted cells, row by row
+    let cells: Vec<Vec<String>> = rowidx.iter().map(|&i| {
+        colidx.iter().map(|&j| match (i, j) {
+            (Some(i), Some(j)) => format!("{:.*}", precision, elem(i, j)),
+            _ => String::from("...")
+        }).collect()
+    }).collect();
+    let widths: Vec<usize> = (0..colidx.len())
+        .map(|j| cells.iter().map(|row| row[j].len()).max().unwrap_or(0))
+        .collect();
+    for (k, row) in cells.iter().enumerate() {
+        if k > 0 {
+            f.write_str("\n")?;
+        }
+        f.write_str("[")?;
+        for (j, cell) in row.iter().enumerate() {
+            if j > 0 {
+                f.write_str("  ")?;
+            }
+            write!(f, "{:>width$}", cell, width = widths[j])?;
+        }
+        f.write_str("]")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,378 @@
+//! QR factorizations with column pivoting and updating.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use libarmasd_sys as ffi;
+
+use super::{OpCodes, PivotOps, Error, blas, lapack};
+use super::dense::{Matrix};
+use super::vec::{Vector, AsVectorMut};
+use super::pivot::{Pivot};
+use super::scalar::{Scalar};
+
+/// QR factorization A = Q*R with explicit m-by-m orthogonal Q and m-by-n upper trapezoidal R.
+///
+/// Factorization can be updated when rows or columns are added to or removed from A without
+/// refactoring the matrix. Updates use Givens rotations and cost O(m^2) operations.
+#[derive(Debug, Clone)]
+pub struct QR<T: Scalar = f64> {
+    q: Matrix<T>,
+    r: Matrix<T>
+}
+
+/// QR factorization with column pivoting A*P = Q*R.
+///
+/// Factorization is stored as with `lapack::qrfactor` and Q can be applied with `lapack::qrmult`.
+#[derive(Debug)]
+pub struct PivotedQR<T: Scalar = f64> {
+    qr: Matrix<T>,
+    tau: Vector<T>,
+    pivot: Pivot,
+    rank: u32
+}
+
+/// Complete orthogonal decomposition A*P = Q*[T11 0; 0 0]*Z where T11 is r-by-r lower
+/// triangular matrix and r is the numerical rank of A.
+#[derive(Debug)]
+pub struct CompleteOrthogonal<T: Scalar = f64> {
+    qrp: PivotedQR<T>,
+    lz: Matrix<T>,
+    tauz: Vector<T>
+}
+
+impl<T: Scalar> PivotedQR<T> {
+    /// Compute QR factorization with column pivoting (Businger-Golub) of matrix. At each
+    /// step the remaining column of largest norm is moved to the front. Numerical rank is the
+    /// number of diagonal elements of R with |R[k,k]| > tol*|R[0,0]|. Default tolerance is
+    /// machine epsilon times max(m, n).
+    pub fn new(A: &Matrix<T>, tol: Option<T>) -> Result<PivotedQR<T>, i32> {
+        let (m, n) = A.size();
+        let K = m.min(n);
+        let mut QR = A.clone();
+        let mut tau = Vector::new(K);
+        let mut pivot = Pivot::new(n);
+        let mut t = Vector::new(1);
+        for j in K..n {
+            pivot.set_raw(j, j as i32 + 1);
+        }
+        for k in 0..K {
+            // pivot column with largest norm of trailing part
+            let tail = QR.submatrix(k, k, m - k, n - k);
+            let mut p = k;
+            let mut pnorm = T::zero();
+            for (j, c) in tail.columns().enumerate() {
+                let cn = blas::norm2(&c)?;
+                if cn > pnorm {
+                    p = k + j as u32;
+                    pnorm = cn;
+                }
+            }
+            pivot.set_raw(k, p as i32 + 1);
+            if p != k {
+                let (mut left, mut right) = QR.split_at_col(p);
+                unsafe {
+                    T::armas_swap(left.column_mut(k).as_mut_ptr(), right.column_mut(0).as_mut_ptr(), ffi::armas_conf_default());
+                }
+            }
+            // reflector for column k applied to trailing columns
+            let (mut left, mut right) = QR.split_at_col(k + 1);
+            let mut col = left.submatrix_mut(k, k, m - k, 1);
+            lapack::qrfactor(&mut col, &mut t)?;
+            tau.set(k, t.get(0));
+            if k + 1 < n {
+                lapack::qrmult(&mut right.row_range_mut(k, m), &col, &t, Some(OpCodes::LEFT|OpCodes::TRANS))?;
+            }
+        }
+
+        let tol = tol.unwrap_or_else(|| T::EPSILON * T::from_f64(m.max(n) as f64));
+        let rmax = if K > 0 { QR.get(0, 0).abs() } else { T::zero() };
+        let rank = (0..K).take_while(|&k| QR.get(k, k).abs() > tol * rmax).count() as u32;
+        Ok(PivotedQR { qr: QR, tau, pivot, rank })
+    }
+
+    /// Factorized matrix, R in upper triangular part and Householder vectors of Q below diagonal.
+    pub fn qr(&self) -> &Matrix<T> {
+        &self.qr
+    }
+
+    pub fn tau(&self) -> &Vector<T> {
+        &self.tau
+    }
+
+    /// Column interchanges, apply with `PivotOps::Columns`.
+    pub fn pivot(&self) -> &Pivot {
+        &self.pivot
+    }
+
+    /// Estimated numerical rank.
+    pub fn rank(&self) -> u32 {
+        self.rank
+    }
+
+    /// Get upper triangular K-by-n matrix R, K = min(m, n).
+    pub fn r(&self) -> Matrix<T> {
+        let (m, n) = self.qr.size();
+        let mut R = self.qr.submatrix(0, 0, m.min(n), n).to_matrix();
+        for (i, j, _) in self.qr.submatrix(0, 0, m.min(n), n).iter() {
+            if i > j {
+                R.set(i, j, T::zero());
+            }
+        }
+        R
+    }
+
+    /// Compute complete orthogonal decomposition by annihilating R12 block of the first rank
+    /// rows of R with LQ factorization.
+    pub fn complete_orthogonal(self) -> Result<CompleteOrthogonal<T>, i32> {
+        let (_, n) = self.qr.size();
+        let r = self.rank;
+        let mut lz = Matrix::new(r, n);
+        for (i, j, v) in self.qr.submatrix(0, 0, r, n).iter() {
+            if i <= j {
+                lz.set(i, j, v);
+            }
+        }
+        let mut tauz = Vector::new(r);
+        lapack::lqfactor(&mut lz, &mut tauz)?;
+        Ok(CompleteOrthogonal { qrp: self, lz, tauz })
+    }
+}
+
+impl<T: Scalar> CompleteOrthogonal<T> {
+    /// Numerical rank of the matrix.
+    pub fn rank(&self) -> u32 {
+        self.qrp.rank
+    }
+
+    /// Pivoted QR factorization the decomposition was computed from.
+    pub fn qrp(&self) -> &PivotedQR<T> {
+        &self.qrp
+    }
+
+    /// Get r-by-r lower triangular matrix T11.
+    pub fn t11(&self) -> Matrix<T> {
+        let r = self.rank();
+        let mut L = self.lz.submatrix(0, 0, r, r).to_matrix();
+        for (i, j, _) in self.lz.submatrix(0, 0, r, r).iter() {
+            if i < j {
+                L.set(i, j, T::zero());
+            }
+        }
+        L
+    }
+
+    /// Compute minimum norm least squares solution X of min ||B - A*X||.
+    pub fn solve(&self, B: &Matrix<T>) -> Result<Matrix<T>, i32> {
+        let (m, n) = self.qrp.qr.size();
+        let (brows, k) = B.size();
+        if brows != m {
+            return Err(Error::ESize as i32);
+        }
+        let r = self.rank();
+        let mut X = Matrix::new(n, k);
+        if r > 0 {
+            let mut C = B.clone();
+            lapack::qrmult(&mut C, &self.qrp.qr, &self.qrp.tau, Some(OpCodes::LEFT|OpCodes::TRANS))?;
+            let mut Y1 = X.row_range_mut(0, r);
+            C.row_range(0, r).copy_to(&mut Y1, super::CopyOps::All);
+            blas::solve_trm(&mut Y1, T::one(), &self.lz.submatrix(0, 0, r, r), Some(OpCodes::LOWER|OpCodes::LEFT))?;
+            lapack::lqmult(&mut X, &self.lz, &self.tauz, Some(OpCodes::LEFT|OpCodes::TRANS))?;
+        }
+        // x = P*y
+        self.qrp.pivot.apply(&mut X, PivotOps::Rows, PivotOps::Backward).map_err(|e| e as i32)?;
+        Ok(X)
+    }
+}
+
+impl<T: Scalar> QR<T> {
+    /// Compute QR factorization of matrix.
+    pub fn new(A: &Matrix<T>) -> Result<QR<T>, i32> {
+        let (m, n) = A.size();
+        let K = m.min(n);
+        let mut F = A.clone();
+        let mut tau = Vector::new(K);
+        lapack::qrfactor(&mut F, &mut tau)?;
+        let mut Q = Matrix::new(m, m);
+        F.column_range(0, K).copy_to(&mut Q.column_range_mut(0, K), super::CopyOps::All);
+        lapack::qrbuild(&mut Q, &tau, K)?;
+        let mut R = Matrix::new(m, n);
+        for (i, j, v) in F.iter() {
+            if i <= j {
+                R.set(i, j, v);
+            }
+        }
+        Ok(QR { q: Q, r: R })
+    }
+
+    /// Orthogonal m-by-m matrix Q.
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+
+    /// Upper trapezoidal m-by-n matrix R.
+    pub fn r(&self) -> &Matrix<T> {
+        &self.r
+    }
+
+    /// Size of the factorized matrix.
+    pub fn size(&self) -> (u32, u32) {
+        self.r.size()
+    }
+
+    /// Compute Q*R.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let (m, n) = self.size();
+        let mut A = Matrix::new(m, n);
+        blas::mult(T::zero(), &mut A, T::one(), &self.q, &self.r, None).expect("QR product failed");
+        A
+    }
+
+    /// Solve least squares problem min ||B - A*X|| for A with full column rank and m >= n.
+    pub fn solve(&self, B: &Matrix<T>) -> Result<Matrix<T>, i32> {
+        let (m, n) = self.size();
+        let (brows, k) = B.size();
+        if brows != m || m < n {
+            return Err(Error::ESize as i32);
+        }
+        let mut C = Matrix::new(m, k);
+        blas::mult(T::zero(), &mut C, T::one(), &self.q, B, Some(OpCodes::TRANSA))?;
+        let mut X = C.row_range(0, n).to_matrix();
+        blas::solve_trm(&mut X, T::one(), &self.r.submatrix(0, 0, n, n), Some(OpCodes::UPPER|OpCodes::LEFT))?;
+        Ok(X)
+    }
+
+    /// Update factorization to A + u*v^T.
+    pub fn rank_one_update(&mut self, u: &Vector<T>, v: &Vector<T>) -> Result<(), i32> {
+        let (m, n) = self.size();
+        if u.size() != m || v.size() != n {
+            return Err(Error::ESize as i32);
+        }
+        // w = Q^T*u, reduce w to multiple of e_1 making R upper Hessenberg
+        let mut w = Vector::new(m);
+        blas::mvmult(T::zero(), &mut w, T::one(), &self.q, u, Some(OpCodes::TRANS))?;
+        for k in (1..m).rev() {
+            let (c, s) = givens(w.get(k - 1), w.get(k));
+            let (w0, w1) = (w.get(k - 1), w.get(k));
+            w.set(k - 1, c * w0 + s * w1);
+            w.set(k, c * w1 - s * w0);
+            self.rotate(k - 1, k, c, s);
+        }
+        if m > 0 {
+            let w0 = w.get(0);
+            blas::axpby(T::one(), &mut self.r.row_mut(0), w0, v)?;
+        }
+        self.retriangulate(0);
+        Ok(())
+    }
+
+    /// Update factorization when row is appended to A.
+    pub fn append_row(&mut self, row: &Vector<T>) -> Result<(), i32> {
+        let (m, n) = self.size();
+        if row.size() != n {
+            return Err(Error::ESize as i32);
+        }
+        let mut Q = Matrix::new(m + 1, m + 1);
+        self.q.copy_to(&mut Q.submatrix_mut(0, 0, m, m), super::CopyOps::All);
+        Q.set(m, m, T::one());
+        let mut R = Matrix::new(m + 1, n);
+        self.r.copy_to(&mut R.row_range_mut(0, m), super::CopyOps::All);
+        row.copy_to(&mut R.row_mut(m));
+        self.q = Q;
+        self.r = R;
+        // zero new row against the diagonal
+        for k in 0..m.min(n) {
+            let (c, s) = givens(self.r.get(k, k), self.r.get(m, k));
+            self.rotate(k, m, c, s);
+        }
+        Ok(())
+    }
+
+    /// Update factorization when i'th row is removed from A.
+    pub fn delete_row(&mut self, i: u32) -> Result<(), i32> {
+        let (m, _) = self.size();
+        if i >= m {
+            return Err(Error::EInval as i32);
+        }
+        // Rotate i'th row of Q to a multiple of e_1
+        for k in (1..m).rev() {
+            let (c, s) = givens(self.q.get(i, k - 1), self.q.get(i, k));
+            self.rotate(k - 1, k, c, s);
+        }
+        // Q = [q 0; Q1 Q2; q' 0] without row i and column 0, R without row 0
+        let mut Q = Matrix::new(m - 1, m - 1);
+        if i > 0 {
+            self.q.submatrix(0, 1, i, m - 1).copy_to(&mut Q.row_range_mut(0, i), super::CopyOps::All);
+        }
+        if i + 1 < m {
+            self.q.submatrix(i + 1, 1, m - i - 1, m - 1).copy_to(&mut Q.row_range_mut(i, m - 1), super::CopyOps::All);
+        }
+        self.r = self.r.row_range(1, m).to_matrix();
+        self.q = Q;
+        Ok(())
+    }
+
+    /// Update factorization when column is appended to A.
+    pub fn append_column(&mut self, col: &Vector<T>) -> Result<(), i32> {
+        let (m, n) = self.size();
+        if col.size() != m {
+            return Err(Error::ESize as i32);
+        }
+        let mut R = Matrix::new(m, n + 1);
+        self.r.copy_to(&mut R.column_range_mut(0, n), super::CopyOps::All);
+        blas::mvmult(T::zero(), &mut R.column_mut(n), T::one(), &self.q, col, Some(OpCodes::TRANS))?;
+        self.r = R;
+        for k in (n + 1..m).rev() {
+            let (c, s) = givens(self.r.get(k - 1, n), self.r.get(k, n));
+            self.rotate(k - 1, k, c, s);
+        }
+        Ok(())
+    }
+
+    /// Update factorization when j'th column is removed from A.
+    pub fn delete_column(&mut self, j: u32) -> Result<(), i32> {
+        let (m, n) = self.size();
+        if j >= n {
+            return Err(Error::EInval as i32);
+        }
+        let mut R = Matrix::new(m, n - 1);
+        if j > 0 {
+            self.r.column_range(0, j).copy_to(&mut R.column_range_mut(0, j), super::CopyOps::All);
+        }
+        if j + 1 < n {
+            self.r.column_range(j + 1, n).copy_to(&mut R.column_range_mut(j, n - 1), super::CopyOps::All);
+        }
+        self.r = R;
+        self.retriangulate(j);
+        Ok(())
+    }
+
+    // Zero subdiagonal of upper Hessenberg R starting from column j.
+    fn retriangulate(&mut self, j: u32) {
+        let (m, n) = self.size();
+        for k in j..n.min(m.saturating_sub(1)) {
+            let (c, s) = givens(self.r.get(k, k), self.r.get(k + 1, k));
+            self.rotate(k, k + 1, c, s);
+        }
+    }
+
+    // Apply rotation G to rows i, k of R and G^T to columns i, k of Q.
+    fn rotate(&mut self, i: u32, k: u32, c: T, s: T) {
+        let (m, n) = self.size();
+        unsafe {
+            T::armas_gvleft(self.r.as_mut_ptr(), c, s, i as i32, k as i32, 0, n as i32);
+            T::armas_gvright(self.q.as_mut_ptr(), c, s, i as i32, k as i32, 0, m as i32);
+        }
+    }
+}
+
+// Compute Givens rotation [c s; -s c] that zeros b in [a; b].
+fn givens<T: Scalar>(a: T, b: T) -> (T, T) {
+    let mut c = T::one();
+    let mut s = T::zero();
+    let mut r = T::zero();
+    unsafe {
+        T::armas_gvcompute(&mut c, &mut s, &mut r, a, b);
+    }
+    (c, s)
+}
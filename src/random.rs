@@ -0,0 +1,123 @@
+//! Random matrices from user provided random number generators.
+//!
+//! Unlike `Matrix::uniform` and `Matrix::normal`, which use the global generator of the armas
+//! library, elements are generated in Rust from a `rand::RngCore` and are reproducible with a
+//! seeded generator. Requires the `random` feature.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use rand::{Rng, RngCore};
+
+use super::{OpCodes, Error, blas, lapack};
+use super::dense::{Matrix, AsMatrix};
+use super::vec::{Vector};
+use super::scalar::{Scalar};
+
+/// Distribution of random elements.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distribution {
+    /// Uniform distribution on [low, high)
+    Uniform { low: f64, high: f64 },
+    /// Normal distribution with mean and standard deviation
+    Normal { mean: f64, std: f64 },
+}
+
+impl Distribution {
+    /// Uniform distribution on [0, 1).
+    pub const UNIFORM: Distribution = Distribution::Uniform { low: 0.0, high: 1.0 };
+    /// Standard normal distribution.
+    pub const NORMAL: Distribution = Distribution::Normal { mean: 0.0, std: 1.0 };
+
+    /// Draw value from distribution.
+    pub fn sample<T: Scalar, R: RngCore + ?Sized>(&self, rng: &mut R) -> T {
+        let v = match *self {
+            Distribution::Uniform { low, high } => low + (high - low) * rng.gen::<f64>(),
+            Distribution::Normal { mean, std } => {
+                // Box-Muller, u1 in (0, 1]
+                let u1 = 1.0 - rng.gen::<f64>();
+                let u2 = rng.gen::<f64>();
+                mean + std * (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+            }
+        };
+        T::from_f64(v)
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Create matrix with elements drawn from distribution.
+    pub fn random_with<R: RngCore + ?Sized>(rows: u32, cols: u32, rng: &mut R, dist: Distribution) -> Matrix<T> {
+        let mut A = Matrix::new(rows, cols);
+        for j in 0..cols {
+            for i in 0..rows {
+                A.set(i, j, dist.sample(rng));
+            }
+        }
+        A
+    }
+}
+
+impl<T: Scalar> Vector<T> {
+    /// Create vector with elements drawn from distribution.
+    pub fn random_with<R: RngCore + ?Sized>(n: u32, rng: &mut R, dist: Distribution) -> Vector<T> {
+        let mut x = Vector::new(n);
+        for k in 0..n {
+            x.set(k, dist.sample(rng));
+        }
+        x
+    }
+}
+
+/// Create n-by-n orthogonal matrix uniformly distributed in Haar measure. Matrix is Q factor
+/// of QR factorization of normal random matrix with signs fixed by diagonal of R.
+pub fn orthogonal<T: Scalar, R: RngCore + ?Sized>(n: u32, rng: &mut R) -> Result<Matrix<T>, i32> {
+    let mut Q = Matrix::random_with(n, n, rng, Distribution::NORMAL);
+    let mut tau = Vector::new(n);
+    lapack::qrfactor(&mut Q, &mut tau)?;
+    let signs: Vec<bool> = (0..n).map(|k| Q.get(k, k) < T::zero()).collect();
+    lapack::qrbuild(&mut Q, &tau, n)?;
+    for (k, &negative) in signs.iter().enumerate() {
+        if negative {
+            blas::scale(&mut Q.column_mut(k as u32), -T::one())?;
+        }
+    }
+    Ok(Q)
+}
+
+/// Create n-by-n random symmetric positive definite matrix with 2-norm condition number cond.
+/// Eigenvalues are geometrically spaced from 1 to 1/cond. Fails with EInval if cond < 1.
+pub fn spd<T: Scalar, R: RngCore + ?Sized>(n: u32, cond: T, rng: &mut R) -> Result<Matrix<T>, i32> {
+    if cond < T::one() {
+        return Err(Error::EInval as i32);
+    }
+    let c = cond.to_f64();
+    let d: Vec<T> = (0..n)
+        .map(|k| if n > 1 { T::from_f64(c.powf(-(k as f64) / (n - 1) as f64)) } else { T::one() })
+        .collect();
+    let Q = orthogonal(n, rng)?;
+    product(Q.clone(), &d, &Q)
+}
+
+/// Create m-by-n random matrix with given singular values, s has min(m, n) elements.
+pub fn with_singular_values<T: Scalar, R: RngCore + ?Sized>(m: u32, n: u32, s: &Vector<T>, rng: &mut R) -> Result<Matrix<T>, i32> {
+    let K = m.min(n);
+    if s.size() != K {
+        return Err(Error::ESize as i32);
+    }
+    let U = orthogonal(m, rng)?;
+    let V = orthogonal(n, rng)?;
+    let d: Vec<T> = s.iter().map(|(_, v)| v).collect();
+    product(U.column_range(0, K).to_matrix(), &d, &V.column_range(0, K))
+}
+
+// Compute U*diag(d)*V^T, U is scaled in place.
+fn product<T: Scalar>(mut U: Matrix<T>, d: &[T], V: &impl AsMatrix<T>) -> Result<Matrix<T>, i32> {
+    let (m, _) = U.size();
+    let (n, _) = V.size();
+    for (k, &v) in d.iter().enumerate() {
+        blas::scale(&mut U.column_mut(k as u32), v)?;
+    }
+    let mut A = Matrix::new(m, n);
+    blas::mult(T::zero(), &mut A, T::one(), &U, V, Some(OpCodes::TRANSB))?;
+    Ok(A)
+}
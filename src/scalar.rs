@@ -0,0 +1,560 @@
+//! Element types supported by the armas libraries.
+//!
+//! Double precision is always available. Single precision requires the `float32`
+//! feature which builds and links the single precision `armass` library.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use libarmasd_sys as ffi;
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+use std::os::raw::{c_int, c_void};
+use serde::{Serialize};
+use serde::de::{DeserializeOwned};
+
+/// Dense matrix descriptor for element type T.
+pub type Dense<T> = ffi::armas_x_dense<T>;
+
+/// Eigenvalue selection parameters for element type T.
+pub type EigenParameter<T> = ffi::armas_x_eigen_parameter<T>;
+
+/// Floating point element type of vectors and matrices.
+///
+/// The `armas_*` functions are the bindings to the armas library compiled for
+/// this element type and are not meant to be called directly. They have the same
+/// safety requirements as the corresponding functions in `libarmasd_sys`.
+#[allow(clippy::missing_safety_doc)]
+pub trait Scalar:
+    Copy + Default + Debug + Display + PartialEq + PartialOrd
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+    + AddAssign + SubAssign + MulAssign + DivAssign
+    + Serialize + DeserializeOwned + 'static
+{
+    /// Machine epsilon.
+    const EPSILON: Self;
+    /// Uniform [0, 1) random number generator of the library.
+    const UNIFORM: unsafe extern "C" fn() -> Self;
+    /// Standard normal random number generator of the library.
+    const NORMAL: unsafe extern "C" fn() -> Self;
+
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_f64(v: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+
+    unsafe fn armas_init(m: *mut Dense<Self>, r: c_int, c: c_int) -> *mut Dense<Self>;
+    unsafe fn armas_make(m: *mut Dense<Self>, r: c_int, c: c_int, s: c_int, buf: *mut Self) -> *mut Dense<Self>;
+    unsafe fn armas_alloc(r: c_int, c: c_int) -> *mut Dense<Self>;
+    unsafe fn armas_release(m: *mut Dense<Self>);
+    unsafe fn armas_free(m: *mut Dense<Self>);
+    unsafe fn armas_set_unsafe(m: *mut Dense<Self>, r: c_int, c: c_int, v: Self);
+    unsafe fn armas_set_at_unsafe(m: *mut Dense<Self>, k: c_int, v: Self);
+    unsafe fn armas_get_unsafe(m: *const Dense<Self>, r: c_int, c: c_int) -> Self;
+    unsafe fn armas_get_at_unsafe(m: *const Dense<Self>, k: c_int) -> Self;
+    unsafe fn armas_row_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, r: c_int) -> *mut Dense<Self>;
+    unsafe fn armas_column_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, c: c_int) -> *mut Dense<Self>;
+    unsafe fn armas_diag_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, k: c_int) -> *mut Dense<Self>;
+    unsafe fn armas_submatrix_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, r: c_int, c: c_int, nr: c_int, nc: c_int) -> *mut Dense<Self>;
+    unsafe fn armas_mcopy(A: *mut Dense<Self>, B: *const Dense<Self>, flags: i32);
+    unsafe fn armas_set_all(m: *mut Dense<Self>, func: unsafe extern "C" fn() -> Self, flags: c_int) -> c_int;
+    unsafe fn armas_set_values(m: *mut Dense<Self>, func: unsafe extern "C" fn(c_int, c_int) -> Self, flags: c_int) -> c_int;
+    unsafe fn armas_make_trm(m: *mut Dense<Self>, flags: c_int);
+    unsafe fn armas_madd(d: *mut Dense<Self>, alpha: Self, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mscale(d: *mut Dense<Self>, alpha: Self, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_scale(d: *mut Dense<Self>, alpha: Self, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mplus(alpha: Self, A: *mut Dense<Self>, beta: Self, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_apply(A: *mut Dense<Self>, func: unsafe extern "C" fn(Self) -> Self, flags: c_int) -> c_int;
+    unsafe fn armas_apply2(A: *mut Dense<Self>, func: unsafe extern "C" fn(Self, *mut c_void) -> Self, arg: *mut c_void, flags: c_int) -> c_int;
+    unsafe fn armas_iterate(A: *const Dense<Self>, func: unsafe extern "C" fn(Self, *mut c_void) -> c_int, p: *mut c_void, flags: c_int) -> c_int;
+    unsafe fn armas_mnorm(A: *const Dense<Self>, norm: c_int, cf: *mut ffi::armas_conf) -> Self;
+    unsafe fn armas_iamax(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_amax(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self;
+    unsafe fn armas_asum(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self;
+    unsafe fn armas_nrm2(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self;
+    unsafe fn armas_dot(X: *const Dense<Self>, Y: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self;
+    unsafe fn armas_adot(result: *mut Self, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_axpy(Y: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_axpby(beta: Self, Y: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_copy(Y: *mut Dense<Self>, X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_swap(Y: *mut Dense<Self>, X: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_sum(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self;
+    unsafe fn armas_mvmult(beta: Self, Y: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, X: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mvupdate(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mvmult_sym(beta: Self, Y: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, X: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mvupdate2_sym(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mvupdate_sym(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mvupdate_trm(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mvmult_trm(X: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mvsolve_trm(X: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mult(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mult_sym(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mult_trm(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_solve_trm(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_update_trm(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_update_sym(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_update2_sym(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_mult_diag(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_solve_diag(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_qrbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_qrfactor(A: *mut Dense<Self>, tau: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_qrmult(C: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int,cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_qrsolve(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_lqbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_lqfactor(A: *mut Dense<Self>, tau: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_lqmult(C: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_lqsolve(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_ldlfactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_ldlsolve(B: *mut Dense<Self>, A: *const Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_bkfactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_bksolve(B: *mut Dense<Self>, A: *const Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_lufactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_lusolve(B: *mut Dense<Self>, A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_hessreduce(A: *mut Dense<Self>, tau: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_hessmult(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_cholesky(A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_cholfactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_cholsolve(B: *mut Dense<Self>, A: *const Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_cholupdate(A: *mut Dense<Self>, X: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_bdreduce(A: *mut Dense<Self>, tauq: *mut Dense<Self>, taup: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_bdbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_bdmult(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_trdreduce(A: *mut Dense<Self>, tau: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_trdbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_trdmult(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_trdeigen(D: *mut Dense<Self>, E: *mut Dense<Self>, V: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_trdbisect(Y: *mut Dense<Self>, D: *mut Dense<Self>, E: *mut Dense<Self>, params: *const EigenParameter<Self>, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_gvcompute(c: *mut Self, s: *mut Self, r: *mut Self, a: Self, b: Self);
+    unsafe fn armas_gvrotate(v0: *mut Self, v1: *mut Self, c: Self, s: Self, y0: Self, y1: Self);
+    unsafe fn armas_gvleft(A: *mut Dense<Self>, c: Self, s: Self, r1: c_int, r2: c_int, col: c_int, ncol: c_int);
+    unsafe fn armas_gvright(A: *mut Dense<Self>, c: Self, s: Self, r1: c_int, r2: c_int, col: c_int, ncol: c_int);
+    unsafe fn armas_gvupdate(A: *mut Dense<Self>, start: c_int, C: *mut Dense<Self>, S: *mut Dense<Self>, nrot: c_int, flags: c_int) -> c_int;
+    unsafe fn armas_gvrot_vec(X: *mut Dense<Self>, Y: *mut Dense<Self>, c: Self, s: Self) -> c_int;
+    unsafe fn armas_bdsvd(D: *mut Dense<Self>, E: *mut Dense<Self>, U: *mut Dense<Self>, V: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_svd(S: *mut Dense<Self>, U: *mut Dense<Self>, V: *mut Dense<Self>, A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_eigen_sym(D: *mut Dense<Self>, A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_eigen_sym_selected(D: *mut Dense<Self>, A: *mut Dense<Self>, params: *const EigenParameter<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_luinverse(A: *mut Dense<Self>, P: *const ffi::armas_pivot, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_cholinverse(A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+    unsafe fn armas_ldlinverse(A: *mut Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int;
+}
+
+macro_rules! impl_scalar {
+    ($t:ty, $($m:ident)::+) => {
+        impl Scalar for $t {
+            const EPSILON: Self = <$t>::EPSILON;
+            const UNIFORM: unsafe extern "C" fn() -> Self = $($m)::+::armas_uniform;
+            const NORMAL: unsafe extern "C" fn() -> Self = $($m)::+::armas_normal;
+
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn from_f64(v: f64) -> Self {
+                v as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            unsafe fn armas_init(m: *mut Dense<Self>, r: c_int, c: c_int) -> *mut Dense<Self> {
+                $($m)::+::armas_init(m, r, c)
+            }
+
+            unsafe fn armas_make(m: *mut Dense<Self>, r: c_int, c: c_int, s: c_int, buf: *mut Self) -> *mut Dense<Self> {
+                $($m)::+::armas_make(m, r, c, s, buf)
+            }
+
+            unsafe fn armas_alloc(r: c_int, c: c_int) -> *mut Dense<Self> {
+                $($m)::+::armas_alloc(r, c)
+            }
+
+            unsafe fn armas_release(m: *mut Dense<Self>) {
+                $($m)::+::armas_release(m)
+            }
+
+            unsafe fn armas_free(m: *mut Dense<Self>) {
+                $($m)::+::armas_free(m)
+            }
+
+            unsafe fn armas_set_unsafe(m: *mut Dense<Self>, r: c_int, c: c_int, v: Self) {
+                $($m)::+::armas_set_unsafe(m, r, c, v)
+            }
+
+            unsafe fn armas_set_at_unsafe(m: *mut Dense<Self>, k: c_int, v: Self) {
+                $($m)::+::armas_set_at_unsafe(m, k, v)
+            }
+
+            unsafe fn armas_get_unsafe(m: *const Dense<Self>, r: c_int, c: c_int) -> Self {
+                $($m)::+::armas_get_unsafe(m, r, c)
+            }
+
+            unsafe fn armas_get_at_unsafe(m: *const Dense<Self>, k: c_int) -> Self {
+                $($m)::+::armas_get_at_unsafe(m, k)
+            }
+
+            unsafe fn armas_row_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, r: c_int) -> *mut Dense<Self> {
+                $($m)::+::armas_row_unsafe(A, B, r)
+            }
+
+            unsafe fn armas_column_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, c: c_int) -> *mut Dense<Self> {
+                $($m)::+::armas_column_unsafe(A, B, c)
+            }
+
+            unsafe fn armas_diag_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, k: c_int) -> *mut Dense<Self> {
+                $($m)::+::armas_diag_unsafe(A, B, k)
+            }
+
+            unsafe fn armas_submatrix_unsafe(A: *mut Dense<Self>, B: *const Dense<Self>, r: c_int, c: c_int, nr: c_int, nc: c_int) -> *mut Dense<Self> {
+                $($m)::+::armas_submatrix_unsafe(A, B, r, c, nr, nc)
+            }
+
+            unsafe fn armas_mcopy(A: *mut Dense<Self>, B: *const Dense<Self>, flags: i32) {
+                $($m)::+::armas_mcopy(A, B, flags)
+            }
+
+            unsafe fn armas_set_all(m: *mut Dense<Self>, func: unsafe extern "C" fn() -> Self, flags: c_int) -> c_int {
+                $($m)::+::armas_set_all(m, func, flags)
+            }
+
+            unsafe fn armas_set_values(m: *mut Dense<Self>, func: unsafe extern "C" fn(c_int, c_int) -> Self, flags: c_int) -> c_int {
+                $($m)::+::armas_set_values(m, func, flags)
+            }
+
+            unsafe fn armas_make_trm(m: *mut Dense<Self>, flags: c_int) {
+                $($m)::+::armas_make_trm(m, flags)
+            }
+
+            unsafe fn armas_madd(d: *mut Dense<Self>, alpha: Self, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_madd(d, alpha, flags, cf)
+            }
+
+            unsafe fn armas_mscale(d: *mut Dense<Self>, alpha: Self, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mscale(d, alpha, flags, cf)
+            }
+
+            unsafe fn armas_scale(d: *mut Dense<Self>, alpha: Self, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_scale(d, alpha, cf)
+            }
+
+            unsafe fn armas_mplus(alpha: Self, A: *mut Dense<Self>, beta: Self, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mplus(alpha, A, beta, B, flags, cf)
+            }
+
+            unsafe fn armas_apply(A: *mut Dense<Self>, func: unsafe extern "C" fn(Self) -> Self, flags: c_int) -> c_int {
+                $($m)::+::armas_apply(A, func, flags)
+            }
+
+            unsafe fn armas_apply2(A: *mut Dense<Self>, func: unsafe extern "C" fn(Self, *mut c_void) -> Self, arg: *mut c_void, flags: c_int) -> c_int {
+                $($m)::+::armas_apply2(A, func, arg, flags)
+            }
+
+            unsafe fn armas_iterate(A: *const Dense<Self>, func: unsafe extern "C" fn(Self, *mut c_void) -> c_int, p: *mut c_void, flags: c_int) -> c_int {
+                $($m)::+::armas_iterate(A, func, p, flags)
+            }
+
+
+
+            unsafe fn armas_mnorm(A: *const Dense<Self>, norm: c_int, cf: *mut ffi::armas_conf) -> Self {
+                $($m)::+::armas_mnorm(A, norm, cf)
+            }
+
+            unsafe fn armas_iamax(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_iamax(X, cf)
+            }
+
+            unsafe fn armas_amax(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self {
+                $($m)::+::armas_amax(X, cf)
+            }
+
+            unsafe fn armas_asum(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self {
+                $($m)::+::armas_asum(X, cf)
+            }
+
+            unsafe fn armas_nrm2(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self {
+                $($m)::+::armas_nrm2(X, cf)
+            }
+
+            unsafe fn armas_dot(X: *const Dense<Self>, Y: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self {
+                $($m)::+::armas_dot(X, Y, cf)
+            }
+
+            unsafe fn armas_adot(result: *mut Self, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_adot(result, alpha, X, Y, cf)
+            }
+
+            unsafe fn armas_axpy(Y: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_axpy(Y, alpha, X, cf)
+            }
+
+            unsafe fn armas_axpby(beta: Self, Y: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_axpby(beta, Y, alpha, X, cf)
+            }
+
+            unsafe fn armas_copy(Y: *mut Dense<Self>, X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_copy(Y, X, cf)
+            }
+
+            unsafe fn armas_swap(Y: *mut Dense<Self>, X: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_swap(Y, X, cf)
+            }
+
+            unsafe fn armas_sum(X: *const Dense<Self>, cf: *mut ffi::armas_conf) -> Self {
+                $($m)::+::armas_sum(X, cf)
+            }
+
+            unsafe fn armas_mvmult(beta: Self, Y: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, X: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvmult(beta, Y, alpha, A, X, flags, cf)
+            }
+
+            unsafe fn armas_mvupdate(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvupdate(beta, A, alpha, X, Y, cf)
+            }
+
+            unsafe fn armas_mvmult_sym(beta: Self, Y: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, X: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvmult_sym(beta, Y, alpha, A, X, flags, cf)
+            }
+
+            unsafe fn armas_mvupdate2_sym(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvupdate2_sym(beta, A, alpha, X, Y, flags, cf)
+            }
+
+            unsafe fn armas_mvupdate_sym(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvupdate_sym(beta, A, alpha, X, flags, cf)
+            }
+
+            unsafe fn armas_mvupdate_trm(beta: Self, A: *mut Dense<Self>, alpha: Self, X: *const Dense<Self>, Y: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvupdate_trm(beta, A, alpha, X, Y, flags, cf)
+            }
+
+            unsafe fn armas_mvmult_trm(X: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvmult_trm(X, alpha, A, flags, cf)
+            }
+
+            unsafe fn armas_mvsolve_trm(X: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mvsolve_trm(X, alpha, A, flags, cf)
+            }
+
+            unsafe fn armas_mult(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mult(beta, C, alpha, A, B, flags, cf)
+            }
+
+            unsafe fn armas_mult_sym(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mult_sym(beta, C, alpha, A, B, flags, cf)
+            }
+
+            unsafe fn armas_mult_trm(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mult_trm(B, alpha, A, flags, cf)
+            }
+
+            unsafe fn armas_solve_trm(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_solve_trm(B, alpha, A, flags, cf)
+            }
+
+            unsafe fn armas_update_trm(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_update_trm(beta, C, alpha, A, B, flags, cf)
+            }
+
+            unsafe fn armas_update_sym(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_update_sym(beta, C, alpha, A, flags, cf)
+            }
+
+            unsafe fn armas_update2_sym(beta: Self, C: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, B: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_update2_sym(beta, C, alpha, A, B, flags, cf)
+            }
+
+            unsafe fn armas_mult_diag(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_mult_diag(B, alpha, A, flags, cf)
+            }
+
+            unsafe fn armas_solve_diag(B: *mut Dense<Self>, alpha: Self, A: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_solve_diag(B, alpha, A, flags, cf)
+            }
+
+            unsafe fn armas_qrbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_qrbuild(A, tau, K, cf)
+            }
+
+            unsafe fn armas_qrfactor(A: *mut Dense<Self>, tau: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_qrfactor(A, tau, cf)
+            }
+
+            unsafe fn armas_qrmult(C: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int,cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_qrmult(C, A, tau, flags, cf)
+            }
+
+            unsafe fn armas_qrsolve(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_qrsolve(B, A, tau, flags, cf)
+            }
+
+            unsafe fn armas_lqbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_lqbuild(A, tau, K, cf)
+            }
+
+            unsafe fn armas_lqfactor(A: *mut Dense<Self>, tau: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_lqfactor(A, tau, cf)
+            }
+
+            unsafe fn armas_lqmult(C: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_lqmult(C, A, tau, flags, cf)
+            }
+
+            unsafe fn armas_lqsolve(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_lqsolve(B, A, tau, flags, cf)
+            }
+
+            unsafe fn armas_ldlfactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_ldlfactor(A, P, flags, cf)
+            }
+
+            unsafe fn armas_ldlsolve(B: *mut Dense<Self>, A: *const Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_ldlsolve(B, A, P, flags, cf)
+            }
+
+            unsafe fn armas_bkfactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_bkfactor(A, P, flags, cf)
+            }
+
+            unsafe fn armas_bksolve(B: *mut Dense<Self>, A: *const Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_bksolve(B, A, P, flags, cf)
+            }
+
+            unsafe fn armas_lufactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_lufactor(A, P, cf)
+            }
+
+            unsafe fn armas_lusolve(B: *mut Dense<Self>, A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_lusolve(B, A, P, flags, cf)
+            }
+
+            unsafe fn armas_hessreduce(A: *mut Dense<Self>, tau: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_hessreduce(A, tau, cf)
+            }
+
+            unsafe fn armas_hessmult(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_hessmult(B, A, tau, flags, cf)
+            }
+
+            unsafe fn armas_cholesky(A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_cholesky(A, flags, cf)
+            }
+
+            unsafe fn armas_cholfactor(A: *mut Dense<Self>, P: *mut ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_cholfactor(A, P, flags, cf)
+            }
+
+            unsafe fn armas_cholsolve(B: *mut Dense<Self>, A: *const Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_cholsolve(B, A, P, flags, cf)
+            }
+
+            unsafe fn armas_cholupdate(A: *mut Dense<Self>, X: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_cholupdate(A, X, flags, cf)
+            }
+
+            unsafe fn armas_bdreduce(A: *mut Dense<Self>, tauq: *mut Dense<Self>, taup: *mut Dense<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_bdreduce(A, tauq, taup, cf)
+            }
+
+            unsafe fn armas_bdbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_bdbuild(A, tau, K, flags, cf)
+            }
+
+            unsafe fn armas_bdmult(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_bdmult(B, A, tau, flags, cf)
+            }
+
+            unsafe fn armas_trdreduce(A: *mut Dense<Self>, tau: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_trdreduce(A, tau, flags, cf)
+            }
+
+            unsafe fn armas_trdbuild(A: *mut Dense<Self>, tau: *const Dense<Self>, K: c_int, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_trdbuild(A, tau, K, flags, cf)
+            }
+
+            unsafe fn armas_trdmult(B: *mut Dense<Self>, A: *const Dense<Self>, tau: *const Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_trdmult(B, A, tau, flags, cf)
+            }
+
+            unsafe fn armas_trdeigen(D: *mut Dense<Self>, E: *mut Dense<Self>, V: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_trdeigen(D, E, V, flags, cf)
+            }
+
+            unsafe fn armas_trdbisect(Y: *mut Dense<Self>, D: *mut Dense<Self>, E: *mut Dense<Self>, params: *const EigenParameter<Self>, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_trdbisect(Y, D, E, params, cf)
+            }
+
+            unsafe fn armas_gvcompute(c: *mut Self, s: *mut Self, r: *mut Self, a: Self, b: Self) {
+                $($m)::+::armas_gvcompute(c, s, r, a, b)
+            }
+
+            unsafe fn armas_gvrotate(v0: *mut Self, v1: *mut Self, c: Self, s: Self, y0: Self, y1: Self) {
+                $($m)::+::armas_gvrotate(v0, v1, c, s, y0, y1)
+            }
+
+            unsafe fn armas_gvleft(A: *mut Dense<Self>, c: Self, s: Self, r1: c_int, r2: c_int, col: c_int, ncol: c_int) {
+                $($m)::+::armas_gvleft(A, c, s, r1, r2, col, ncol)
+            }
+
+            unsafe fn armas_gvright(A: *mut Dense<Self>, c: Self, s: Self, r1: c_int, r2: c_int, col: c_int, ncol: c_int) {
+                $($m)::+::armas_gvright(A, c, s, r1, r2, col, ncol)
+            }
+
+            unsafe fn armas_gvupdate(A: *mut Dense<Self>, start: c_int, C: *mut Dense<Self>, S: *mut Dense<Self>, nrot: c_int, flags: c_int) -> c_int {
+                $($m)::+::armas_gvupdate(A, start, C, S, nrot, flags)
+            }
+
+            unsafe fn armas_gvrot_vec(X: *mut Dense<Self>, Y: *mut Dense<Self>, c: Self, s: Self) -> c_int {
+                $($m)::+::armas_gvrot_vec(X, Y, c, s)
+            }
+
+            unsafe fn armas_bdsvd(D: *mut Dense<Self>, E: *mut Dense<Self>, U: *mut Dense<Self>, V: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_bdsvd(D, E, U, V, flags, cf)
+            }
+
+            unsafe fn armas_svd(S: *mut Dense<Self>, U: *mut Dense<Self>, V: *mut Dense<Self>, A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_svd(S, U, V, A, flags, cf)
+            }
+
+            unsafe fn armas_eigen_sym(D: *mut Dense<Self>, A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_eigen_sym(D, A, flags, cf)
+            }
+
+            unsafe fn armas_eigen_sym_selected(D: *mut Dense<Self>, A: *mut Dense<Self>, params: *const EigenParameter<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_eigen_sym_selected(D, A, params, flags, cf)
+            }
+
+            unsafe fn armas_luinverse(A: *mut Dense<Self>, P: *const ffi::armas_pivot, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_luinverse(A, P, cf)
+            }
+
+            unsafe fn armas_cholinverse(A: *mut Dense<Self>, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_cholinverse(A, flags, cf)
+            }
+
+            unsafe fn armas_ldlinverse(A: *mut Dense<Self>, P: *const ffi::armas_pivot, flags: c_int, cf: *mut ffi::armas_conf) -> c_int {
+                $($m)::+::armas_ldlinverse(A, P, flags, cf)
+            }
+        }
+    }
+}
+
+impl_scalar!(f64, ffi);
+
+#[cfg(feature = "float32")]
+impl_scalar!(f32, ffi::float32);
@@ -0,0 +1,252 @@
+//! Descriptive statistics of data matrices.
+//!
+//! Data matrices hold observations in rows and variables in columns. Covariance estimates use
+//! the unbiased 1/(n-1) normalization.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use super::{OpCodes, Error, blas, lapack};
+use super::dense::{Matrix};
+use super::vec::{Vector};
+use super::scalar::{Scalar};
+
+/// Principal component analysis of data matrix.
+#[derive(Debug, Clone)]
+pub struct Pca<T: Scalar = f64> {
+    /// Column means of data.
+    pub mean: Vector<T>,
+    /// Principal axes as columns of p-by-k matrix.
+    pub components: Matrix<T>,
+    /// Variance explained by each component.
+    pub explained_variance: Vector<T>,
+    /// Fraction of total variance explained by each component.
+    pub explained_variance_ratio: Vector<T>,
+    /// Centered data projected on principal axes, n-by-k matrix.
+    pub scores: Matrix<T>
+}
+
+/// Multivariate normal distribution N(mean, cov).
+#[derive(Debug, Clone)]
+pub struct MultivariateNormal<T: Scalar = f64> {
+    mean: Vector<T>,
+    cov: Matrix<T>,
+    // lower Cholesky factor of covariance
+    chol: Matrix<T>,
+    logdet: T
+}
+
+/// Mean of each row of matrix.
+pub fn mean_rows<T: Scalar>(X: &Matrix<T>) -> Vector<T> {
+    let (rows, cols) = X.size();
+    let mut mean = Vector::new(rows);
+    for (i, _, v) in X.iter() {
+        mean.set(i, mean.get(i) + v);
+    }
+    if cols > 0 {
+        blas::scale(&mut mean, T::one() / T::from_f64(cols as f64)).expect("scaling failed");
+    }
+    mean
+}
+
+/// Mean of each column of matrix.
+pub fn mean_cols<T: Scalar>(X: &Matrix<T>) -> Vector<T> {
+    let (rows, cols) = X.size();
+    let mut mean = Vector::new(cols);
+    for (_, j, v) in X.iter() {
+        mean.set(j, mean.get(j) + v);
+    }
+    if rows > 0 {
+        blas::scale(&mut mean, T::one() / T::from_f64(rows as f64)).expect("scaling failed");
+    }
+    mean
+}
+
+/// Subtract column means from matrix. Returns the column means.
+pub fn center<T: Scalar>(X: &mut Matrix<T>) -> Vector<T> {
+    let mean = mean_cols(X);
+    let (rows, cols) = X.size();
+    for j in 0..cols {
+        let mu = mean.get(j);
+        for i in 0..rows {
+            X.set(i, j, X.get(i, j) - mu);
+        }
+    }
+    mean
+}
+
+/// Compute p-by-p sample covariance matrix of n-by-p data matrix.
+pub fn covariance<T: Scalar>(X: &Matrix<T>) -> Result<Matrix<T>, i32> {
+    let (n, p) = X.size();
+    if n < 2 {
+        return Err(Error::ESize as i32);
+    }
+    let mut Xc = X.clone();
+    center(&mut Xc);
+    let mut C = Matrix::new(p, p);
+    blas::update_sym(T::zero(), &mut C, T::one() / T::from_f64((n - 1) as f64), &Xc, Some(OpCodes::LOWER|OpCodes::TRANS))?;
+    for j in 0..p {
+        for i in j + 1..p {
+            C.set(j, i, C.get(i, j));
+        }
+    }
+    Ok(C)
+}
+
+/// Compute p-by-p correlation matrix of n-by-p data matrix. Fails with EInval if some
+/// variable is constant.
+pub fn correlation<T: Scalar>(X: &Matrix<T>) -> Result<Matrix<T>, i32> {
+    let mut C = covariance(X)?;
+    let (p, _) = C.size();
+    let d: Vec<T> = (0..p).map(|k| C.get(k, k).sqrt()).collect();
+    if d.iter().any(|&v| v == T::zero()) {
+        return Err(Error::EInval as i32);
+    }
+    for j in 0..p {
+        for i in 0..p {
+            let v = if i == j { T::one() } else { C.get(i, j) / (d[i as usize] * d[j as usize]) };
+            C.set(i, j, v);
+        }
+    }
+    Ok(C)
+}
+
+/// Compute k first principal components of n-by-p data matrix with SVD of centered data.
+pub fn pca<T: Scalar>(X: &Matrix<T>, k: u32) -> Result<Pca<T>, i32> {
+    let (n, p) = X.size();
+    let K = n.min(p);
+    if n < 2 || k > K {
+        return Err(Error::ESize as i32);
+    }
+    let mut Xc = X.clone();
+    let mean = center(&mut Xc);
+    let mut s = Vector::new(K);
+    let mut U = Matrix::new(n, K);
+    let mut Vt = Matrix::new(K, p);
+    lapack::svd(&mut s, &mut U, &mut Vt, &mut Xc, Some(OpCodes::WANTU|OpCodes::WANTV))?;
+
+    let scale = T::one() / T::from_f64((n - 1) as f64);
+    let total = s.iter().fold(T::zero(), |acc, (_, v)| acc + v * v) * scale;
+    let mut components = Matrix::new(p, k);
+    let mut explained_variance = Vector::new(k);
+    let mut explained_variance_ratio = Vector::new(k);
+    let mut scores = Matrix::new(n, k);
+    for j in 0..k {
+        let sj = s.get(j);
+        let var = sj * sj * scale;
+        explained_variance.set(j, var);
+        explained_variance_ratio.set(j, if total > T::zero() { var / total } else { T::zero() });
+        for i in 0..p {
+            components.set(i, j, Vt.get(j, i));
+        }
+        for i in 0..n {
+            scores.set(i, j, U.get(i, j) * sj);
+        }
+    }
+    Ok(Pca { mean, components, explained_variance, explained_variance_ratio, scores })
+}
+
+/// Whiten n-by-p data matrix. Centered data is transformed with inverse of Cholesky factor
+/// of sample covariance C = L*L^T, giving data with identity covariance.
+pub fn whiten<T: Scalar>(X: &Matrix<T>) -> Result<Matrix<T>, i32> {
+    let mut L = covariance(X)?;
+    lapack::cholesky(&mut L, Some(OpCodes::LOWER))?;
+    let mut W = X.clone();
+    center(&mut W);
+    // rows w^T = x^T*L^-T
+    blas::solve_trm(&mut W, T::one(), &L, Some(OpCodes::LOWER|OpCodes::TRANS|OpCodes::RIGHT))?;
+    Ok(W)
+}
+
+/// Compute Mahalanobis distance of each row of n-by-p matrix X from mean with covariance
+/// matrix C. Fails with ENegative if C is not positive definite.
+pub fn mahalanobis<T: Scalar>(X: &Matrix<T>, mean: &Vector<T>, C: &Matrix<T>) -> Result<Vector<T>, i32> {
+    let (n, p) = X.size();
+    if mean.size() != p || C.size() != (p, p) {
+        return Err(Error::ESize as i32);
+    }
+    let mut L = C.clone();
+    lapack::cholesky(&mut L, Some(OpCodes::LOWER))?;
+    let mut D = X.clone();
+    for (i, j, v) in X.iter() {
+        D.set(i, j, v - mean.get(j));
+    }
+    blas::solve_trm(&mut D, T::one(), &L, Some(OpCodes::LOWER|OpCodes::TRANS|OpCodes::RIGHT))?;
+    let mut dist: Vector<T> = Vector::new(n);
+    for (i, _, v) in D.iter() {
+        dist.set(i, dist.get(i) + v * v);
+    }
+    for i in 0..n {
+        dist.set(i, dist.get(i).sqrt());
+    }
+    Ok(dist)
+}
+
+impl<T: Scalar> MultivariateNormal<T> {
+    /// Create distribution with mean vector and symmetric positive definite covariance matrix.
+    /// Covariance elements are read from the lower triangular part. Fails with ENegative if
+    /// covariance is not positive definite.
+    pub fn new(mean: Vector<T>, cov: Matrix<T>) -> Result<MultivariateNormal<T>, i32> {
+        let p = mean.size();
+        if cov.size() != (p, p) {
+            return Err(Error::ESize as i32);
+        }
+        let mut chol = cov.clone();
+        lapack::cholesky(&mut chol, Some(OpCodes::LOWER))?;
+        let logdet = lapack::logdet_cholesky(&chol);
+        Ok(MultivariateNormal { mean, cov, chol, logdet })
+    }
+
+    pub fn mean(&self) -> &Vector<T> {
+        &self.mean
+    }
+
+    pub fn cov(&self) -> &Matrix<T> {
+        &self.cov
+    }
+
+    /// Dimension of the distribution.
+    pub fn dim(&self) -> u32 {
+        self.mean.size()
+    }
+
+    /// Draw sample x = mean + L*z with z i.i.d. standard normal.
+    pub fn sample(&self) -> Vector<T> {
+        let mut x = Vector::normal(self.dim());
+        blas::mvmult_trm(&mut x, T::one(), &self.chol, Some(OpCodes::LOWER)).expect("sampling failed");
+        blas::axpby(T::one(), &mut x, T::one(), &self.mean).expect("sampling failed");
+        x
+    }
+
+    /// Draw n samples as rows of n-by-p matrix.
+    pub fn sample_n(&self, n: u32) -> Matrix<T> {
+        let p = self.dim();
+        let mut X = Matrix::normal(n, p);
+        // rows x^T = z^T*L^T
+        blas::mult_trm(&mut X, T::one(), &self.chol, Some(OpCodes::LOWER|OpCodes::TRANS|OpCodes::RIGHT))
+            .expect("sampling failed");
+        for i in 0..n {
+            blas::axpby(T::one(), &mut X.row_mut(i), T::one(), &self.mean).expect("sampling failed");
+        }
+        X
+    }
+
+    /// Natural logarithm of probability density at x.
+    pub fn log_pdf(&self, x: &Vector<T>) -> Result<T, i32> {
+        let p = self.dim();
+        if x.size() != p {
+            return Err(Error::ESize as i32);
+        }
+        let mut d = x.clone();
+        blas::axpby(T::one(), &mut d, -T::one(), &self.mean)?;
+        blas::mvsolve_trm(&mut d, T::one(), &self.chol, Some(OpCodes::LOWER))?;
+        let quad = blas::dot(&d, &d);
+        let log2pi = T::from_f64((2.0 * std::f64::consts::PI).ln());
+        Ok(-T::from_f64(0.5) * (T::from_f64(p as f64) * log2pi + self.logdet + quad))
+    }
+
+    /// Probability density at x.
+    pub fn pdf(&self, x: &Vector<T>) -> Result<T, i32> {
+        Ok(T::from_f64(self.log_pdf(x)?.to_f64().exp()))
+    }
+}
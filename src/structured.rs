@@ -0,0 +1,246 @@
+//! Structured matrix types carrying triangular and symmetric storage flags at type level.
+//!
+//! Matrices are dense matrices with only the referenced triangle or diagonal in use.
+//! Conversion from a full matrix zeros the unused triangle.
+
+// Allow non_snake_case variables to use upper case characters as identifier for Matrix type arguments.
+#![allow(non_snake_case)]
+
+use std::marker::PhantomData;
+use std::ops::Mul;
+
+use super::{OpCodes, Error, blas};
+use super::dense::{Matrix};
+use super::vec::{Vector};
+use super::scalar::{Scalar};
+
+/// Storage triangle of triangular and symmetric matrices.
+pub trait Uplo {
+    const FLAGS: OpCodes;
+}
+
+/// Diagonal type of triangular matrices.
+pub trait Diag {
+    const FLAGS: OpCodes;
+}
+
+/// Lower triangular storage.
+#[derive(Debug, Clone, Copy)]
+pub struct Lower;
+
+/// Upper triangular storage.
+#[derive(Debug, Clone, Copy)]
+pub struct Upper;
+
+/// Unit diagonal, diagonal elements are ones.
+#[derive(Debug, Clone, Copy)]
+pub struct Unit;
+
+/// Non-unit diagonal.
+#[derive(Debug, Clone, Copy)]
+pub struct NonUnit;
+
+impl Uplo for Lower {
+    const FLAGS: OpCodes = OpCodes::LOWER;
+}
+
+impl Uplo for Upper {
+    const FLAGS: OpCodes = OpCodes::UPPER;
+}
+
+impl Diag for Unit {
+    const FLAGS: OpCodes = OpCodes::UNIT;
+}
+
+impl Diag for NonUnit {
+    const FLAGS: OpCodes = OpCodes::NOTRANS;
+}
+
+/// Triangular matrix.
+#[derive(Debug, Clone)]
+pub struct Triangular<U: Uplo, D: Diag = NonUnit, T: Scalar = f64> {
+    mat: Matrix<T>,
+    _marker: PhantomData<(U, D)>
+}
+
+/// Symmetric matrix with elements stored in lower or upper triangle.
+#[derive(Debug, Clone)]
+pub struct Symmetric<U: Uplo, T: Scalar = f64> {
+    mat: Matrix<T>,
+    _marker: PhantomData<U>
+}
+
+/// Diagonal matrix.
+#[derive(Debug, Clone)]
+pub struct Diagonal<T: Scalar = f64> {
+    diag: Vector<T>
+}
+
+// Zero the unused triangle of square matrix.
+fn make_trm<T: Scalar>(mut A: Matrix<T>, flags: OpCodes) -> Result<Matrix<T>, Error> {
+    let (rows, cols) = A.size();
+    if rows != cols {
+        return Err(Error::ESize);
+    }
+    unsafe {
+        T::armas_make_trm(A.as_mut_ptr(), flags.bits());
+    }
+    Ok(A)
+}
+
+impl<U: Uplo, D: Diag, T: Scalar> Triangular<U, D, T> {
+    /// Create triangular matrix from square matrix. Elements outside the triangle are
+    /// set to zero and, for unit diagonal, diagonal elements to one.
+    pub fn from_matrix(A: Matrix<T>) -> Result<Triangular<U, D, T>, Error> {
+        let mat = make_trm(A, U::FLAGS | D::FLAGS)?;
+        Ok(Triangular { mat, _marker: PhantomData })
+    }
+
+    /// Operation flags of the matrix.
+    pub fn flags(&self) -> OpCodes {
+        U::FLAGS | D::FLAGS
+    }
+
+    // Operation flags with the side of multiplication.
+    fn side(&self, side: OpCodes) -> OpCodes {
+        self.flags() | side
+    }
+
+    pub fn as_matrix(&self) -> &Matrix<T> {
+        &self.mat
+    }
+
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.mat
+    }
+
+    /// Solve A*X = B, B is overwritten with the solution.
+    pub fn solve(&self, B: &mut Matrix<T>) -> Result<(), Error> {
+        blas::solve_trm(B, T::one(), &self.mat, Some(self.side(OpCodes::LEFT))).map_err(Error::from)
+    }
+
+    /// Solve A*x = b, b is overwritten with the solution.
+    pub fn solve_vector(&self, b: &mut Vector<T>) -> Result<(), Error> {
+        blas::mvsolve_trm(b, T::one(), &self.mat, Some(self.flags())).map_err(Error::from)
+    }
+}
+
+impl<U: Uplo, D: Diag, T: Scalar> Mul<&Matrix<T>> for &Triangular<U, D, T> {
+    type Output = Matrix<T>;
+
+    /// Compute A*B. Panics if operands are not conformant.
+    fn mul(self, B: &Matrix<T>) -> Matrix<T> {
+        let mut C = B.clone();
+        blas::mult_trm(&mut C, T::one(), &self.mat, Some(self.side(OpCodes::LEFT)))
+            .expect("triangular matrix product failed");
+        C
+    }
+}
+
+impl<U: Uplo, D: Diag, T: Scalar> Mul<&Vector<T>> for &Triangular<U, D, T> {
+    type Output = Vector<T>;
+
+    /// Compute A*x. Panics if operands are not conformant.
+    fn mul(self, x: &Vector<T>) -> Vector<T> {
+        let mut y = x.clone();
+        blas::mvmult_trm(&mut y, T::one(), &self.mat, Some(self.flags()))
+            .expect("triangular matrix-vector product failed");
+        y
+    }
+}
+
+impl<U: Uplo, T: Scalar> Symmetric<U, T> {
+    /// Create symmetric matrix from square matrix. Elements outside the storage triangle
+    /// are set to zero.
+    pub fn from_matrix(A: Matrix<T>) -> Result<Symmetric<U, T>, Error> {
+        let mat = make_trm(A, U::FLAGS)?;
+        Ok(Symmetric { mat, _marker: PhantomData })
+    }
+
+    /// Operation flags of the matrix.
+    pub fn flags(&self) -> OpCodes {
+        U::FLAGS
+    }
+
+    // Operation flags with the side of multiplication.
+    fn side(&self, side: OpCodes) -> OpCodes {
+        self.flags() | side
+    }
+
+    pub fn as_matrix(&self) -> &Matrix<T> {
+        &self.mat
+    }
+
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.mat
+    }
+}
+
+impl<U: Uplo, T: Scalar> Mul<&Matrix<T>> for &Symmetric<U, T> {
+    type Output = Matrix<T>;
+
+    /// Compute A*B. Panics if operands are not conformant.
+    fn mul(self, B: &Matrix<T>) -> Matrix<T> {
+        let (rows, _) = self.mat.size();
+        let (_, cols) = B.size();
+        let mut C = Matrix::new(rows, cols);
+        blas::mult_sym(T::zero(), &mut C, T::one(), &self.mat, B, Some(self.side(OpCodes::LEFT)))
+            .expect("symmetric matrix product failed");
+        C
+    }
+}
+
+impl<U: Uplo, T: Scalar> Mul<&Vector<T>> for &Symmetric<U, T> {
+    type Output = Vector<T>;
+
+    /// Compute A*x. Panics if operands are not conformant.
+    fn mul(self, x: &Vector<T>) -> Vector<T> {
+        let (rows, _) = self.mat.size();
+        let mut y = Vector::new(rows);
+        blas::mvmult_sym(T::zero(), &mut y, T::one(), &self.mat, x, Some(self.flags()))
+            .expect("symmetric matrix-vector product failed");
+        y
+    }
+}
+
+impl<T: Scalar> Diagonal<T> {
+    /// Create diagonal matrix with elements of vector on diagonal.
+    pub fn from_vector(d: Vector<T>) -> Diagonal<T> {
+        Diagonal { diag: d }
+    }
+
+    /// Create diagonal matrix from main diagonal of square matrix.
+    pub fn from_matrix(A: &Matrix<T>) -> Result<Diagonal<T>, Error> {
+        let (rows, cols) = A.size();
+        if rows != cols {
+            return Err(Error::ESize);
+        }
+        Ok(Diagonal { diag: A.diagonal(0).to_vector() })
+    }
+
+    pub fn as_vector(&self) -> &Vector<T> {
+        &self.diag
+    }
+
+    /// Create dense matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_diagonal(&self.diag)
+    }
+
+    /// Solve D*X = B, B is overwritten with the solution.
+    pub fn solve(&self, B: &mut Matrix<T>) -> Result<(), Error> {
+        blas::solve_diag(B, T::one(), &self.diag, Some(OpCodes::LEFT)).map_err(Error::from)
+    }
+}
+
+impl<T: Scalar> Mul<&Matrix<T>> for &Diagonal<T> {
+    type Output = Matrix<T>;
+
+    /// Compute D*B. Panics if operands are not conformant.
+    fn mul(self, B: &Matrix<T>) -> Matrix<T> {
+        let mut C = B.clone();
+        blas::mult_diag(&mut C, T::one(), &self.diag, Some(OpCodes::LEFT))
+            .expect("diagonal matrix product failed");
+        C
+    }
+}
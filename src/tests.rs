@@ -27,7 +27,7 @@ extern fn one() -> f64 {
 
 #[test]
 fn test_create() {
-    let mat = dense::Matrix::new(5, 5);
+    let mat: dense::Matrix = dense::Matrix::new(5, 5);
     let (rows, cols) = mat.size();
     assert_eq!(rows, 5);
     assert_eq!(cols, 5);
@@ -35,15 +35,106 @@ fn test_create() {
     assert_eq!(mat.get(4, 4), 0.0);
 }
 
+#[test]
+fn test_constructors() {
+    let z: vec::Vector = vec::Vector::zeros(4);
+    assert!(z.iter().all(|(_, v)| v == 0.0));
+    let o: dense::Matrix = dense::Matrix::ones(3, 2);
+    assert!(o.iter().all(|(_, _, v)| v == 1.0));
+    let f: dense::Matrix = dense::Matrix::filled(2, 2, 3.0);
+    assert_eq!(f.get(1, 1), 3.0);
+
+    let e: dense::Matrix = dense::Matrix::eye(3, 4);
+    for (i, j, v) in e.iter() {
+        assert_eq!(v, if i == j { 1.0 } else { 0.0 });
+    }
+    let d = vec::Vector::new_from(vec![1.0, 2.0, 3.0]);
+    let dm = dense::Matrix::from_diagonal(&d);
+    assert_eq!(dm.size(), (3, 3));
+    assert_eq!(dm.get(2, 2), 3.0);
+    assert_eq!(dm.get(0, 2), 0.0);
+    assert_eq!(dense::Matrix::<f64>::identity(3).get(1, 1), 1.0);
+
+    let mut u: dense::Matrix = unsafe { dense::Matrix::uninit(2, 3) };
+    for j in 0..3 {
+        for i in 0..2 {
+            u.set(i, j, (i + 2 * j) as f64);
+        }
+    }
+    let uc = u.clone();
+    assert!(uc.iter().all(|(i, j, v)| v == (i + 2 * j) as f64));
+    let mut x: vec::Vector = unsafe { vec::Vector::uninit(3) };
+    for k in 0..3 {
+        x.set(k, k as f64);
+    }
+    assert_eq!(x.clone().get(2), 2.0);
+}
+
+#[test]
+fn test_try_from() {
+    use std::convert::TryFrom;
+    use super::Error;
+
+    let a: dense::Matrix = dense::Matrix::try_from_vec(2, 3, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]).unwrap();
+    let b = dense::Matrix::from_row_major(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    let c = dense::Matrix::from_rows(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]).unwrap();
+    let d = dense::Matrix::from_columns(&[&[1.0, 4.0], &[2.0, 5.0], &[3.0, 6.0]]).unwrap();
+    let e = dense::Matrix::try_from(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    for m in &[&b, &c, &d, &e] {
+        assert_eq!(m.size(), (2, 3));
+        assert!(a.iter().all(|(i, j, v)| m.get(i, j) == v));
+    }
+    assert_eq!(a.get(0, 2), 3.0);
+
+    assert_eq!(dense::Matrix::<f64>::try_from_vec(2, 3, vec![1.0; 5]).unwrap_err(), Error::ESize);
+    assert_eq!(dense::Matrix::from_rows(&[&[1.0, 2.0], &[3.0]]).unwrap_err(), Error::ESize);
+    assert_eq!(dense::Matrix::try_from(vec![vec![1.0], vec![]]).unwrap_err(), Error::ESize);
+
+    let exact: dense::Matrix = dense::Matrix::new_from(2, 2, vec![1.0; 4]);
+    assert_eq!(exact.size(), (2, 2));
+    let wrapped: dense::Matrix = dense::Matrix::new_from(65536, 65536, Vec::new());
+    assert_eq!(wrapped.size(), (0, 0));
+    let bad: Result<dense::Matrix, _> = serde_json::from_str(r#"{"rows":2,"cols":2,"data":[1.0]}"#);
+    assert!(bad.is_err());
+}
+
+#[test]
+fn test_slice_views() {
+    use super::Error;
+
+    // 2x3 matrix with leading dimension 3, padding row holds -1.0
+    let mut buf = vec![1.0, 4.0, -1.0, 2.0, 5.0, -1.0, 3.0, 6.0];
+    {
+        let v = dense::MatrixView::from_slice(&buf, 2, 3, 3).unwrap();
+        assert_eq!(v.size(), (2, 3));
+        assert_eq!(v.get(1, 2), 6.0);
+        assert!(v.iter().all(|(_, _, x)| x > 0.0));
+        assert_eq!(v.diagonal(0).get(1), 5.0);
+        assert_eq!(v.submatrix(0, 1, 2, 2).column(1).to_vector().get(1), 6.0);
+    }
+    {
+        let mut v = dense::MatrixViewMut::from_slice(&mut buf, 2, 3, 3).unwrap();
+        v.set(0, 1, 7.0);
+        v.diagonal_mut(0).set(1, 8.0);
+        blas::scale(&mut v.column_mut(2), 2.0).unwrap();
+    }
+    assert_eq!(buf[3], 7.0);
+    assert_eq!(buf[4], 8.0);
+    assert_eq!(buf[2], -1.0);
+    assert_eq!(&buf[6..], &[6.0, 12.0]);
+
+    assert_eq!(dense::MatrixView::from_slice(&buf, 3, 3, 3).err(), Some(Error::ESize));
+    assert_eq!(dense::MatrixView::from_slice(&buf, 3, 2, 2).err(), Some(Error::EInval));
+}
+
 #[test]
 fn test_views() {
     let mut mat = dense::Matrix::new(5, 5);
-    let mut d = mat.diagonal(0);
-    assert_eq!(d.size(), 5);
+    assert_eq!(mat.diagonal(0).size(), 5);
 
     mat.set(3, 3, 5.0);
     assert_eq!(mat.get(3, 3), 5.0);
-    assert_eq!(d.get(3), 5.0);
+    assert_eq!(mat.diagonal(0).get(3), 5.0);
 
     let r = mat.row(3);
     assert_eq!(r.get(3), 5.0);
@@ -51,20 +142,93 @@ fn test_views() {
     let c = mat.column(3);
     assert_eq!(c.get(3), 5.0);
 
-    d.set(3, 10.0);
+    mat.diagonal_mut(0).set(3, 10.0);
     assert_eq!(mat.get(3, 3), 10.0);
 }
 
+#[test]
+fn test_row_column_views() {
+    let mut a = dense::Matrix::from_rows(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]).unwrap();
+    let c = a.column(2);
+    assert_eq!(c.size(), 2);
+    assert_eq!(c.get(1), 6.0);
+    let r = a.row(1);
+    assert_eq!(r.size(), 3);
+    assert_eq!(r.get(0), 4.0);
+
+    assert_eq!(a.rows().count(), 2);
+    let sums: Vec<f64> = a.columns().map(|c| c.iter().map(|(_, v)| v).sum()).collect();
+    assert_eq!(sums, vec![5.0, 7.0, 9.0]);
+
+    assert_eq!(a.row_range(1, 2).size(), (1, 3));
+    assert_eq!(a.column_range(1, 3).get(0, 0), 2.0);
+
+    {
+        let (mut left, mut right) = a.split_at_col(1);
+        assert_eq!(left.size(), (2, 1));
+        assert_eq!(right.size(), (2, 2));
+        left.set(0, 0, 10.0);
+        right.set(0, 0, 20.0);
+    }
+    {
+        let (top, bottom) = a.split_at_row(1);
+        assert_eq!(top.get(0, 1), 20.0);
+        assert_eq!(bottom.size(), (1, 3));
+    }
+    assert_eq!(a.get(0, 0), 10.0);
+
+    for (k, mut c) in a.columns_mut().enumerate() {
+        c.set(1, k as f64);
+    }
+    a.row_mut(0).set(2, 30.0);
+    a.diagonal_mut(0).set(1, 11.0);
+    assert_eq!(a.get(1, 2), 2.0);
+    assert_eq!(a.get(0, 2), 30.0);
+    assert_eq!(a.get(1, 1), 11.0);
+    let mut b = a.row_range_mut(1, 2);
+    b.column_range_mut(0, 2).set(0, 0, -1.0);
+    assert_eq!(b.row_range(0, 1).get(0, 2), 2.0);
+    assert_eq!(a.get(1, 0), -1.0);
+}
+
+#[test]
+#[should_panic(expected = "row range out of bounds")]
+fn test_row_range_out_of_bounds() {
+    let a: dense::Matrix = dense::Matrix::new(3, 3);
+    a.row_range(2, 1);
+}
+
+#[test]
+#[should_panic(expected = "submatrix out of bounds")]
+fn test_submatrix_out_of_bounds() {
+    let a: dense::Matrix = dense::Matrix::new(3, 3);
+    a.row_range(1, 10);
+}
+
+#[test]
+#[should_panic(expected = "column out of bounds")]
+fn test_column_out_of_bounds() {
+    let mut a: dense::Matrix = dense::Matrix::new(3, 3);
+    a.column_mut(7);
+}
+
+#[test]
+#[should_panic(expected = "diagonal out of bounds")]
+fn test_diagonal_out_of_bounds() {
+    let a: dense::Matrix = dense::Matrix::new(3, 2);
+    a.diagonal(2);
+}
+
 #[test]
 fn test_iter() {
-    let a0 = dense::Matrix::uniform(4, 3);
+    let a0: dense::Matrix = dense::Matrix::uniform(4, 3);
 
     for e in &a0 {
         assert!((e.0 < 4 && e.1 < 3));
         assert!((e.2  >= 0.0 && e.2 < 1.0));
     }
 
-    let v0 = vec::Vector::uniform(4);
+    let v0: vec::Vector = vec::Vector::uniform(4);
     for e in &v0 {
         assert!((e.1  >= 0.0 && e.1 < 1.0));
     }
@@ -97,6 +261,23 @@ fn test_blas_mult() {
     // println!("n0: {}, n1: {}, n1/n0: {}", n0, n1, n1/n0);
 }
 
+#[cfg(feature = "float32")]
+#[test]
+fn test_blas_mult_f32() {
+    let a0 = dense::Matrix::<f32>::uniform(M, K);
+    let a1 = dense::Matrix::<f32>::uniform(K, N);
+    let mut r0 = dense::Matrix::<f32>::new(M, N);
+    let mut r1 = dense::Matrix::<f32>::new(N, M);
+
+    blas::mult(0.0, &mut r0, 1.0, &a0, &a1, None).unwrap();
+    blas::mult(0.0, &mut r1, 1.0, &a1, &a0, Some(OpCodes::TRANSA|OpCodes::TRANSB)).unwrap();
+
+    blas::mplus(1.0, &mut r1, -1.0, &r0, Some(OpCodes::TRANSB)).unwrap();
+    let n0 = blas::mnorm(&r0, Norms::Infinity).unwrap();
+    let n1 = blas::mnorm(&r1, Norms::Infinity).unwrap();
+    assert!((n1/n0 < 2e-7));
+}
+
 #[test]
 fn test_serialize() {
     let mut v = vec::Vector::uniform(5);
@@ -112,4 +293,802 @@ fn test_serialize() {
     let n1 = blas::norm2(&v1).unwrap();
     // println!("|v - v1|_2 = {}", n0/n1);
     assert!((n0/n1 < 2e-16));
-}
\ No newline at end of file
+}
+#[cfg(feature = "accelerators")]
+#[test]
+fn test_accelerator() {
+    let ac = super::conf::Accelerator::threaded(2).unwrap();
+    assert_eq!(ac.workers().unwrap(), 2);
+}
+
+#[test]
+fn test_partition() {
+    use super::{partition, lapack};
+
+    let mut a: dense::Matrix = dense::Matrix::from_rows(&[&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]]).unwrap();
+    {
+        let p = partition::partition_2x2(&mut a, 1, 2);
+        assert_eq!(p.tl.size(), (1, 2));
+        assert_eq!(p.tr.size(), (1, 1));
+        assert_eq!(p.bl.size(), (1, 2));
+        assert_eq!(p.br.get(0, 0), 6.0);
+    }
+
+    // blocked right-looking Cholesky factorization
+    let n = 7;
+    let b: dense::Matrix = dense::Matrix::uniform(n, n);
+    let mut spd = dense::Matrix::identity(n);
+    blas::mult(n as f64, &mut spd, 1.0, &b, &b, Some(OpCodes::TRANSB)).unwrap();
+    let mut expect = spd.clone();
+    lapack::cholesky(&mut expect, Some(OpCodes::LOWER)).unwrap();
+
+    let mut blocks = partition::repartition(&mut spd, 3);
+    let mut count = 0;
+    while let Some(mut p) = blocks.next_block() {
+        lapack::cholesky(&mut p.a11, Some(OpCodes::LOWER)).unwrap();
+        blas::solve_trm(&mut p.a21, 1.0, &p.a11, Some(OpCodes::RIGHT|OpCodes::LOWER|OpCodes::TRANSA)).unwrap();
+        blas::update_sym(1.0, &mut p.a22, -1.0, &p.a21, Some(OpCodes::LOWER)).unwrap();
+        count += 1;
+    }
+    assert_eq!(count, 3);
+    assert_eq!(blocks.position(), n);
+    for (i, j, v) in expect.iter() {
+        if i >= j {
+            assert!((spd.get(i, j) - v).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_structured() {
+    use super::structured::{Triangular, Symmetric, Diagonal, Lower, Upper, Unit, NonUnit};
+    use super::Error;
+
+    let a: dense::Matrix = dense::Matrix::from_rows(&[&[2.0, 9.0], &[3.0, 4.0]]).unwrap();
+    let b = dense::Matrix::from_rows(&[&[1.0, 2.0], &[3.0, 4.0]]).unwrap();
+
+    let l: Triangular<Lower> = Triangular::from_matrix(a.clone()).unwrap();
+    assert_eq!(l.as_matrix().get(0, 1), 0.0);
+    let c = &l * &b;
+    assert_eq!(c.get(1, 0), 15.0);
+    let mut x = c.clone();
+    l.solve(&mut x).unwrap();
+    assert!(x.iter().all(|(i, j, v)| (v - b.get(i, j)).abs() < 1e-12));
+
+    let u: Triangular<Upper, Unit> = Triangular::from_matrix(a.clone()).unwrap();
+    assert_eq!(u.as_matrix().get(1, 1), 1.0);
+    assert_eq!(u.as_matrix().get(1, 0), 0.0);
+    let y = &u * &vec::Vector::new_from(vec![1.0, 1.0]);
+    assert_eq!(y.get(0), 10.0);
+    let mut z = y.clone();
+    u.solve_vector(&mut z).unwrap();
+    assert!(z.iter().all(|(_, v)| (v - 1.0).abs() < 1e-12));
+
+    let s: Symmetric<Lower> = Symmetric::from_matrix(a.clone()).unwrap();
+    let c = &s * &b;
+    assert_eq!(c.get(0, 0), 2.0 + 3.0 * 3.0);
+    let y = &s * &vec::Vector::new_from(vec![1.0, 1.0]);
+    assert_eq!(y.get(0), 5.0);
+
+    let d = Diagonal::from_matrix(&a).unwrap();
+    let mut c = &d * &b;
+    assert_eq!(c.get(1, 1), 16.0);
+    d.solve(&mut c).unwrap();
+    assert_eq!(c.get(1, 1), 4.0);
+
+    let r: Result<Triangular<Lower, NonUnit>, _> = Triangular::from_matrix(dense::Matrix::new(2, 3));
+    assert_eq!(r.err().map(|e| e as i32), Some(Error::ESize as i32));
+    assert_eq!(Error::from(Error::ESingular as i32), Error::ESingular);
+    assert_eq!(Error::from(-1), Error::EInval);
+}
+
+#[test]
+fn test_banded() {
+    use super::banded::{Tridiagonal, Banded};
+
+    let n = 6;
+    let t: Tridiagonal = Tridiagonal::new(
+        vec::Vector::filled(n - 1, -1.0), vec::Vector::filled(n, 2.0), vec::Vector::filled(n - 1, -1.0)).unwrap();
+    let a = t.to_matrix();
+    assert_eq!(Tridiagonal::from_matrix(&a).unwrap().to_matrix().get(1, 0), -1.0);
+
+    let x0: vec::Vector = vec::Vector::uniform(n);
+    let mut b = vec::Vector::new(n);
+    t.mvmult(0.0, &mut b, 1.0, &x0).unwrap();
+    let mut c = vec::Vector::new(n);
+    blas::mvmult(0.0, &mut c, 1.0, &a, &x0, None).unwrap();
+    assert!(b.iter().all(|(i, v)| (v - c.get(i)).abs() < 1e-12));
+    t.solve_vector(&mut b).unwrap();
+    assert!(b.iter().all(|(i, v)| (v - x0.get(i)).abs() < 1e-12));
+
+    // eigenvalues of the second difference matrix: 2 - 2cos(k*pi/(n+1))
+    let mut v = dense::Matrix::new(n, n);
+    let d = t.eigen_sym(&mut v).unwrap();
+    let mut expect: Vec<f64> = (1..=n).map(|k| 2.0 - 2.0*(k as f64 * std::f64::consts::PI / (n + 1) as f64).cos()).collect();
+    let mut got: Vec<f64> = d.iter().map(|(_, v)| v).collect();
+    expect.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    got.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!(got.iter().zip(expect.iter()).all(|(g, e)| (g - e).abs() < 1e-10));
+    let mut av = dense::Matrix::new(n, n);
+    blas::mult(0.0, &mut av, 1.0, &a, &v, None).unwrap();
+    assert!(av.column(0).iter().all(|(i, x)| (x - d.get(0) * v.get(i, 0)).abs() < 1e-10));
+    let skew: Tridiagonal = Tridiagonal::new(
+        vec::Vector::filled(n - 1, -1.0), vec::Vector::filled(n, 2.0), vec::Vector::filled(n - 1, 1.0)).unwrap();
+    assert_eq!(skew.eigenvalues().err(), Some(super::Error::EInval));
+    assert_eq!(t.eigen_sym(&mut dense::Matrix::new(2, 2)).err(), Some(super::Error::ESize));
+    assert!(t.eigenvalues().unwrap().iter().all(|(k, x)| x == d.get(k)));
+
+    // eigenvectors written in place to a block of larger matrix
+    let mut w = dense::Matrix::new(n + 1, n);
+    t.eigen_sym(&mut w.row_range_mut(1, n + 1)).unwrap();
+    assert!(w.row(0).iter().all(|(_, x)| x == 0.0));
+    assert!(w.iter().all(|(i, j, x)| i == 0 || x == v.get(i - 1, j)));
+
+    // banded matrix needing row interchanges
+    let mut full: dense::Matrix = dense::Matrix::uniform(n, n);
+    for (i, j, _) in full.clone().iter() {
+        if i > j + 2 || j > i + 1 {
+            full.set(i, j, 0.0);
+        }
+    }
+    full.set(0, 0, 0.0);
+    let band = Banded::from_matrix(&full, 2, 1).unwrap();
+    assert_eq!(band.to_matrix().get(3, 1), full.get(3, 1));
+    assert_eq!(band.get(5, 0), 0.0);
+
+    let x0m: dense::Matrix = dense::Matrix::uniform(n, 2);
+    let mut bm = dense::Matrix::new(n, 2);
+    blas::mult(0.0, &mut bm, 1.0, &full, &x0m, None).unwrap();
+    band.solve(&mut bm).unwrap();
+    assert!(bm.iter().all(|(i, j, v)| (v - x0m.get(i, j)).abs() < 1e-10));
+
+    let mut y = vec::Vector::new(n);
+    band.mvmult(0.0, &mut y, 1.0, &x0m.column(1).to_vector()).unwrap();
+    band.solve_vector(&mut y).unwrap();
+    assert!(y.iter().all(|(i, v)| (v - x0m.get(i, 1)).abs() < 1e-10));
+}
+
+#[test]
+fn test_pivot() {
+    use super::{lapack, PivotOps};
+    use super::pivot::Pivot;
+
+    let n = 5;
+    let a: dense::Matrix = dense::Matrix::uniform(n, n);
+    let mut lu = a.clone();
+    let mut p = Pivot::new(n);
+    lapack::lufactor(&mut lu, &mut p).unwrap();
+    assert_eq!(p.len(), n);
+    assert!(p.iter().enumerate().all(|(k, i)| i >= k as u32 && i < n));
+
+    // P*A = L*U
+    let pm: dense::Matrix = p.to_matrix();
+    let mut pa = dense::Matrix::new(n, n);
+    blas::mult(0.0, &mut pa, 1.0, &pm, &a, None).unwrap();
+    let mut l = lu.clone();
+    for (i, j, _) in lu.iter() {
+        if i < j { l.set(i, j, 0.0); }
+        if i == j { l.set(i, j, 1.0); }
+    }
+    let mut u = lu.clone();
+    for (i, j, _) in lu.iter() {
+        if i > j { u.set(i, j, 0.0); }
+    }
+    let mut prod = dense::Matrix::new(n, n);
+    blas::mult(0.0, &mut prod, 1.0, &l, &u, None).unwrap();
+    assert!(prod.iter().all(|(i, j, v)| (v - pa.get(i, j)).abs() < 1e-12));
+
+    let mut b = a.clone();
+    p.apply(&mut b, PivotOps::Rows, PivotOps::Forward).unwrap();
+    assert!(b.iter().all(|(i, j, v)| v == pa.get(i, j)));
+    let perm = p.to_permutation_vec();
+    assert!(b.iter().all(|(i, j, v)| v == a.get(perm[i as usize], j)));
+    p.apply(&mut b, PivotOps::Rows, PivotOps::Backward).unwrap();
+    assert!(b.iter().all(|(i, j, v)| v == a.get(i, j)));
+    assert!(p.apply(&mut b, PivotOps::Upper, PivotOps::Forward).is_err());
+
+    let q: Pivot = serde_json::from_str(r#"{"pivots":[2,2,3]}"#).unwrap();
+    assert_eq!(q.sign(), -1);
+    assert_eq!(q.to_permutation_vec(), vec![1, 0, 2]);
+
+    assert_eq!(q.iter().collect::<Vec<u32>>(), vec![1, 1, 2]);
+    assert_eq!(serde_json::to_string(&q).unwrap(), r#"{"pivots":[2,2,3]}"#);
+    for bad in &[r#"{"pivots":[0,1]}"#, r#"{"pivots":[3,1]}"#, r#"{"pivots":[1,-3]}"#, r#"{"pivots":[-2147483648]}"#] {
+        assert!(serde_json::from_str::<Pivot>(bad).is_err());
+    }
+    assert!(serde_json::from_str::<Pivot>(r#"{"pivots":[-2,-2]}"#).is_ok());
+}
+
+#[test]
+fn test_det() {
+    use super::lapack;
+
+    let a: dense::Matrix = dense::Matrix::from_rows(&[&[0.0, 2.0, 1.0], &[3.0, 1.0, 0.0], &[1.0, 1.0, 1.0]]).unwrap();
+    // 0*(1-0) - 2*(3-0) + 1*(3-1) = -4
+    assert!((lapack::det(&a).unwrap() + 4.0).abs() < 1e-12);
+    let (sign, logabs) = lapack::slogdet(&a).unwrap();
+    assert_eq!(sign, -1.0);
+    assert!((logabs - 4.0f64.ln()).abs() < 1e-12);
+
+    let singular: dense::Matrix = dense::Matrix::ones(3, 3);
+    assert_eq!(lapack::det(&singular).unwrap(), 0.0);
+    assert!(lapack::det(&dense::Matrix::<f64>::new(2, 3)).is_err());
+
+    let spd: dense::Matrix<f64> = dense::Matrix::from_rows(&[&[4.0, 2.0, 0.0], &[2.0, 5.0, 1.0], &[0.0, 1.0, 3.0]]).unwrap();
+    let expect = lapack::det(&spd).unwrap().ln();
+    assert!((lapack::logdet_spd(&spd, Some(OpCodes::LOWER)).unwrap() - expect).abs() < 1e-12);
+    assert!((lapack::logdet_spd(&spd, Some(OpCodes::UPPER)).unwrap() - expect).abs() < 1e-12);
+    assert!(lapack::logdet_spd(&a, Some(OpCodes::LOWER)).is_err());
+
+    let sym: dense::Matrix<f64> = dense::Matrix::from_rows(&[&[1.0, 2.0, 0.0], &[2.0, 1.0, 1.0], &[0.0, 1.0, 2.0]]).unwrap();
+    let (sign, logabs) = lapack::slogdet_ldl(&sym, Some(OpCodes::LOWER)).unwrap();
+    let (esign, elogabs) = lapack::slogdet(&sym).unwrap();
+    assert_eq!(sign, esign);
+    assert!((logabs - elogabs).abs() < 1e-12);
+}
+
+#[test]
+fn test_lstsq() {
+    use super::lstsq;
+
+    let close = |a: &dense::Matrix, b: &dense::Matrix, tol: f64| a.iter().all(|(i, j, v)| (v - b.get(i, j)).abs() < tol);
+
+    // y = 1 + 2*t with residuals orthogonal to [1, t]
+    let t = [0.0, 1.0, 2.0, 3.0];
+    let e = [1.0, -1.0, -1.0, 1.0];
+    let a = dense::Matrix::from_columns(&[&[1.0; 4], &t]).unwrap();
+    let ys: Vec<f64> = t.iter().zip(e.iter()).map(|(t, e)| 1.0 + 2.0*t + 0.1*e).collect();
+    let b = dense::Matrix::from_columns(&[&ys]).unwrap();
+    let expect = dense::Matrix::from_columns(&[&[1.0, 2.0]]).unwrap();
+
+    let qr = lstsq::solve_qr(&a, &b).unwrap();
+    assert!(close(&qr.x, &expect, 1e-12));
+    assert!((qr.rss.get(0) - 0.04).abs() < 1e-12);
+    assert_eq!(qr.rank, 2);
+
+    // (A^T A)^{-1} = [[4, 6], [6, 14]]^{-1}
+    let cov = dense::Matrix::from_rows(&[&[0.7, -0.3], &[-0.3, 0.2]]).unwrap();
+    assert!(close(qr.covariance.as_ref().unwrap(), &cov, 1e-12));
+    assert!(close(&lstsq::covariance(&a).unwrap(), &cov, 1e-12));
+
+    let sv = lstsq::solve_svd(&a, &b, None).unwrap();
+    assert!(close(&sv.x, &expect, 1e-12));
+    assert!((sv.rss.get(0) - 0.04).abs() < 1e-12);
+    assert!(close(sv.covariance.as_ref().unwrap(), &cov, 1e-12));
+
+    let w = lstsq::weighted(&a, &b, &vec::Vector::ones(4)).unwrap();
+    assert!(close(&w.x, &expect, 1e-12));
+    let mut w0 = vec::Vector::ones(4);
+    w0.set(0, 0.0);
+    assert!(lstsq::weighted(&a, &b, &w0).unwrap().rss.get(0) < 0.04);
+
+    let r0 = lstsq::ridge(&a, &b, 0.0).unwrap();
+    assert!(close(&r0.x, &expect, 1e-12));
+    let r1 = lstsq::ridge(&a, &b, 1.0).unwrap();
+    assert!(blas::mnorm(&r1.x, Norms::Frobenius).unwrap() < blas::mnorm(&r0.x, Norms::Frobenius).unwrap());
+    assert!(r1.rss.get(0) > r0.rss.get(0));
+
+    // rank deficient: duplicated column
+    let ad = dense::Matrix::from_columns(&[&t, &t]).unwrap();
+    let sd = lstsq::solve_svd(&ad, &b, None).unwrap();
+    assert_eq!(sd.rank, 1);
+    assert!(sd.covariance.is_none());
+    assert!((sd.x.get(0, 0) - sd.x.get(1, 0)).abs() < 1e-12);
+
+    // underdetermined: minimum norm solution of x0 + x1 + x2 = 3 is [1, 1, 1]
+    let au = dense::Matrix::from_rows(&[&[1.0, 1.0, 1.0]]).unwrap();
+    let bu = dense::Matrix::filled(1, 1, 3.0);
+    let u = lstsq::solve_qr(&au, &bu).unwrap();
+    assert!(close(&u.x, &dense::Matrix::ones(3, 1), 1e-12));
+    assert!(lstsq::solve_qr(&a, &bu).is_err());
+}
+
+#[test]
+fn test_lse_glm() {
+    use super::{lstsq, lapack};
+    use super::pivot::Pivot;
+
+    // Solve square system K*z = r
+    let kkt = |k: &dense::Matrix, r: &dense::Matrix| {
+        let mut lu = k.clone();
+        let mut z = r.clone();
+        let mut p = Pivot::new(k.size().0);
+        lapack::lufactor(&mut lu, &mut p).unwrap();
+        lapack::lusolve(&mut z, &mut lu, &mut p, None).unwrap();
+        z
+    };
+
+    // LSE: m = 5, n = 3, p = 1
+    let a: dense::Matrix = dense::Matrix::uniform(5, 3);
+    let c: dense::Matrix = dense::Matrix::uniform(5, 1);
+    let b: dense::Matrix = dense::Matrix::from_rows(&[&[1.0, 1.0, 1.0]]).unwrap();
+    let d: dense::Matrix = dense::Matrix::filled(1, 1, 1.0);
+    let sol = lstsq::lse(&a, &c, &b, &d).unwrap();
+    let sum: f64 = sol.x.iter().map(|(_, _, v)| v).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+
+    // [A^T*A B^T; B 0] [x; l] = [A^T*c; d]
+    let mut k = dense::Matrix::new(4, 4);
+    blas::mult(0.0, &mut k.submatrix_mut(0, 0, 3, 3), 1.0, &a, &a, Some(OpCodes::TRANSA)).unwrap();
+    b.copy_to(&mut k.submatrix_mut(3, 0, 1, 3), super::CopyOps::All);
+    for j in 0..3 {
+        k.set(j, 3, b.get(0, j));
+    }
+    let mut r = dense::Matrix::new(4, 1);
+    blas::mult(0.0, &mut r.submatrix_mut(0, 0, 3, 1), 1.0, &a, &c, Some(OpCodes::TRANSA)).unwrap();
+    r.set(3, 0, 1.0);
+    let z = kkt(&k, &r);
+    assert!(sol.x.iter().all(|(i, _, v)| (v - z.get(i, 0)).abs() < 1e-10));
+    let mut res = c.clone();
+    blas::mult(1.0, &mut res, -1.0, &a, &sol.x, None).unwrap();
+    assert!((blas::mnorm(&res, Norms::Frobenius).unwrap().powi(2) - sol.rss.get(0)).abs() < 1e-10);
+    assert!(lstsq::lse(&a, &c, &dense::Matrix::new(4, 3), &dense::Matrix::new(4, 1)).is_err());
+
+    // GLM: n = 4, m = 2, p = 3
+    let a: dense::Matrix = dense::Matrix::uniform(4, 2);
+    let b: dense::Matrix = dense::Matrix::uniform(4, 3);
+    let d: dense::Matrix = dense::Matrix::uniform(4, 1);
+    let (x, y) = lstsq::glm(&a, &b, &d).unwrap();
+    let mut res = d.clone();
+    blas::mult(1.0, &mut res, -1.0, &a, &x, None).unwrap();
+    blas::mult(1.0, &mut res, -1.0, &b, &y, None).unwrap();
+    assert!(res.iter().all(|(_, _, v)| v.abs() < 1e-10));
+
+    // [0 0 A^T; 0 I B^T; A B 0] [x; y; l] = [0; 0; d]
+    let mut k = dense::Matrix::new(9, 9);
+    a.copy_to(&mut k.submatrix_mut(5, 0, 4, 2), super::CopyOps::All);
+    b.copy_to(&mut k.submatrix_mut(5, 2, 4, 3), super::CopyOps::All);
+    for (i, j, v) in a.iter() {
+        k.set(j, 5 + i, v);
+    }
+    for (i, j, v) in b.iter() {
+        k.set(2 + j, 5 + i, v);
+    }
+    for i in 2..5 {
+        k.set(i, i, 1.0);
+    }
+    let mut r = dense::Matrix::new(9, 1);
+    d.copy_to(&mut r.submatrix_mut(5, 0, 4, 1), super::CopyOps::All);
+    let z = kkt(&k, &r);
+    assert!(x.iter().all(|(i, _, v)| (v - z.get(i, 0)).abs() < 1e-10));
+    assert!(y.iter().all(|(i, _, v)| (v - z.get(i + 2, 0)).abs() < 1e-10));
+}
+
+#[test]
+fn test_pivoted_qr() {
+    use super::{lstsq, lapack, PivotOps};
+    use super::qr::PivotedQR;
+
+    let m = 6;
+    let mut a: dense::Matrix = dense::Matrix::uniform(m, 4);
+    // third column is sum of first two, rank 3
+    for i in 0..m {
+        a.set(i, 2, a.get(i, 0) + a.get(i, 1));
+    }
+    let qrp = PivotedQR::new(&a, None).unwrap();
+    assert_eq!(qrp.rank(), 3);
+    assert_eq!(qrp.pivot().len(), 4);
+    let r = qrp.r();
+    assert!(r.get(3, 3).abs() < 1e-12);
+    assert!(r.get(0, 0).abs() >= r.get(1, 1).abs() && r.get(1, 1).abs() >= r.get(2, 2).abs());
+
+    // A*P = Q*R
+    let mut ap = a.clone();
+    qrp.pivot().apply(&mut ap, PivotOps::Columns, PivotOps::Forward).unwrap();
+    let mut qr = dense::Matrix::new(m, 4);
+    r.copy_to(&mut qr.row_range_mut(0, 4), super::CopyOps::All);
+    lapack::qrmult(&mut qr, qrp.qr(), qrp.tau(), Some(OpCodes::LEFT)).unwrap();
+    assert!(qr.iter().all(|(i, j, v)| (v - ap.get(i, j)).abs() < 1e-12));
+
+    let b: dense::Matrix = dense::Matrix::uniform(m, 2);
+    let cod = qrp.complete_orthogonal().unwrap();
+    assert_eq!(cod.t11().size(), (3, 3));
+    let x = cod.solve(&b).unwrap();
+    let expect = lstsq::solve_svd(&a, &b, Some(1e-10)).unwrap();
+    assert!(x.iter().all(|(i, j, v)| (v - expect.x.get(i, j)).abs() < 1e-10));
+
+    // full rank matches QR solution
+    let f: dense::Matrix = dense::Matrix::uniform(m, 3);
+    let x = PivotedQR::new(&f, None).unwrap().complete_orthogonal().unwrap().solve(&b).unwrap();
+    let expect = lstsq::solve_qr(&f, &b).unwrap();
+    assert!(x.iter().all(|(i, j, v)| (v - expect.x.get(i, j)).abs() < 1e-10));
+}
+
+#[test]
+fn test_qr_update() {
+    use super::qr::QR;
+
+    // Q orthogonal and Q*R equals a
+    fn check(qr: &QR, a: &dense::Matrix) {
+        let (m, _) = a.size();
+        let mut qtq = dense::Matrix::new(m, m);
+        blas::mult(0.0, &mut qtq, 1.0, qr.q(), qr.q(), Some(OpCodes::TRANSA)).unwrap();
+        assert!(qtq.iter().all(|(i, j, v)| (v - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12));
+        assert!(qr.r().iter().all(|(i, j, v)| i <= j || v.abs() < 1e-12));
+        let b = qr.to_matrix();
+        assert_eq!(b.size(), a.size());
+        assert!(b.iter().all(|(i, j, v)| (v - a.get(i, j)).abs() < 1e-12));
+    }
+
+    let a: dense::Matrix = dense::Matrix::uniform(6, 4);
+    let mut qr = QR::new(&a).unwrap();
+    check(&qr, &a);
+
+    // a + u*v^T
+    let u: vec::Vector = vec::Vector::uniform(6);
+    let v: vec::Vector = vec::Vector::uniform(4);
+    let mut a1 = a.clone();
+    for i in 0..6 {
+        for j in 0..4 {
+            a1.set(i, j, a.get(i, j) + u.get(i) * v.get(j));
+        }
+    }
+    qr.rank_one_update(&u, &v).unwrap();
+    check(&qr, &a1);
+
+    // append and delete row
+    let row: vec::Vector = vec::Vector::uniform(4);
+    let mut a2 = dense::Matrix::new(7, 4);
+    a1.copy_to(&mut a2.row_range_mut(0, 6), super::CopyOps::All);
+    row.copy_to(&mut a2.row_mut(6));
+    qr.append_row(&row).unwrap();
+    check(&qr, &a2);
+    qr.delete_row(2).unwrap();
+    let mut a3 = dense::Matrix::new(6, 4);
+    a2.row_range(0, 2).copy_to(&mut a3.row_range_mut(0, 2), super::CopyOps::All);
+    a2.row_range(3, 7).copy_to(&mut a3.row_range_mut(2, 6), super::CopyOps::All);
+    check(&qr, &a3);
+
+    // append and delete column
+    let col: vec::Vector = vec::Vector::uniform(6);
+    let mut a4 = dense::Matrix::new(6, 5);
+    a3.copy_to(&mut a4.column_range_mut(0, 4), super::CopyOps::All);
+    col.copy_to(&mut a4.column_mut(4));
+    qr.append_column(&col).unwrap();
+    check(&qr, &a4);
+    qr.delete_column(1).unwrap();
+    let mut a5 = dense::Matrix::new(6, 4);
+    a4.column_range(0, 1).copy_to(&mut a5.column_range_mut(0, 1), super::CopyOps::All);
+    a4.column_range(2, 5).copy_to(&mut a5.column_range_mut(1, 4), super::CopyOps::All);
+    check(&qr, &a5);
+
+    // least squares solution matches refactored matrix
+    let b: dense::Matrix = dense::Matrix::uniform(6, 2);
+    let x = qr.solve(&b).unwrap();
+    let expect = QR::new(&a5).unwrap().solve(&b).unwrap();
+    assert!(x.iter().all(|(i, j, v)| (v - expect.get(i, j)).abs() < 1e-10));
+    assert!(qr.delete_row(6).is_err());
+}
+
+#[test]
+fn test_cholupdate() {
+    use super::{lapack, Error};
+
+    let n = 5;
+    let b: dense::Matrix = dense::Matrix::uniform(n, n);
+    let mut a = dense::Matrix::identity(n);
+    blas::mult(1.0, &mut a, 1.0, &b, &b, Some(OpCodes::TRANSB)).unwrap();
+    let x: dense::Matrix = dense::Matrix::uniform(n, 2);
+    // a + x*x^T
+    let mut axx = a.clone();
+    blas::mult(1.0, &mut axx, 1.0, &x, &x, Some(OpCodes::TRANSB)).unwrap();
+    let tril = |m: &dense::Matrix| -> Vec<f64> { m.iter().filter(|&(i, j, _)| i >= j).map(|(_, _, v)| v).collect() };
+
+    let mut l = axx.clone();
+    lapack::cholesky(&mut l, Some(OpCodes::LOWER)).unwrap();
+    let mut lu = a.clone();
+    lapack::cholesky(&mut lu, Some(OpCodes::LOWER)).unwrap();
+    lapack::cholupdate_k(&mut lu, &x, Some(OpCodes::LOWER)).unwrap();
+    assert!(tril(&lu).iter().zip(tril(&l)).all(|(u, v)| (u - v).abs() < 1e-10));
+
+    // downdating in both columns returns factor of a
+    let mut la = a.clone();
+    lapack::cholesky(&mut la, Some(OpCodes::LOWER)).unwrap();
+    let mut xd = x.clone();
+    for k in 0..2 {
+        lapack::choldowndate(&mut l, &mut xd.column_mut(k), Some(OpCodes::LOWER)).unwrap();
+    }
+    assert!(tril(&l).iter().zip(tril(&la)).all(|(u, v)| (u - v).abs() < 1e-10));
+
+    // downdate to indefinite fails and keeps factor
+    let mut big: vec::Vector = vec::Vector::new(n);
+    big.set(0, 2.0 * la.get(0, 0));
+    let saved = tril(&la);
+    assert_eq!(lapack::choldowndate(&mut la, &mut big, Some(OpCodes::LOWER)), Err(Error::ENegative as i32));
+    assert_eq!(tril(&la), saved);
+
+    // update factor stored as block of larger matrix
+    let mut w = dense::Matrix::new(n + 1, n);
+    la.copy_to(&mut w.row_range_mut(1, n + 1), super::CopyOps::All);
+    lapack::cholupdate_k(&mut w.row_range_mut(1, n + 1), &x, Some(OpCodes::LOWER)).unwrap();
+    assert!(tril(&w.row_range(1, n + 1).to_matrix()).iter().zip(tril(&lu)).all(|(u, v)| (u - v).abs() < 1e-10));
+    assert_eq!(lapack::cholupdate_k(&mut w, &dense::Matrix::new(n + 1, 1), None), Err(Error::ESize as i32));
+    assert_eq!(lapack::cholupdate_k(&mut la, &dense::Matrix::new(n + 1, 1), None), Err(Error::ESize as i32));
+}
+
+#[test]
+fn test_eigen_sym_generalized() {
+    use super::lapack::{self, GeneralizedProblem};
+
+    let n = 4;
+    let c: dense::Matrix = dense::Matrix::uniform(n, n);
+    let mut a = dense::Matrix::new(n, n);
+    blas::mult(0.0, &mut a, 1.0, &c, &c, Some(OpCodes::TRANSA)).unwrap();
+    let g: dense::Matrix = dense::Matrix::uniform(n, n);
+    let mut b = dense::Matrix::identity(n);
+    blas::mult(1.0, &mut b, 1.0, &g, &g, Some(OpCodes::TRANSB)).unwrap();
+
+    let problems = [GeneralizedProblem::AxBx, GeneralizedProblem::ABx, GeneralizedProblem::BAx];
+    for &problem in problems.iter() {
+        let (d, x) = lapack::eigen_sym_generalized(&a, &b, problem, Some(OpCodes::LOWER)).unwrap();
+        // residual of each eigenpair
+        let (mut lhs, mut rhs) = (dense::Matrix::new(n, n), dense::Matrix::new(n, n));
+        let mut t = dense::Matrix::new(n, n);
+        match problem {
+            GeneralizedProblem::AxBx => {
+                blas::mult(0.0, &mut lhs, 1.0, &a, &x, None).unwrap();
+                blas::mult(0.0, &mut rhs, 1.0, &b, &x, None).unwrap();
+            },
+            GeneralizedProblem::ABx => {
+                blas::mult(0.0, &mut t, 1.0, &b, &x, None).unwrap();
+                blas::mult(0.0, &mut lhs, 1.0, &a, &t, None).unwrap();
+                x.copy_to(&mut rhs, super::CopyOps::All);
+            },
+            GeneralizedProblem::BAx => {
+                blas::mult(0.0, &mut t, 1.0, &a, &x, None).unwrap();
+                blas::mult(0.0, &mut lhs, 1.0, &b, &t, None).unwrap();
+                x.copy_to(&mut rhs, super::CopyOps::All);
+            }
+        }
+        assert!(lhs.iter().all(|(i, j, v)| (v - d.get(j) * rhs.get(i, j)).abs() < 1e-8));
+    }
+
+    // B-orthonormal eigenvectors, upper storage gives same eigenvalues
+    let (d, x) = lapack::eigen_sym_generalized(&a, &b, GeneralizedProblem::AxBx, None).unwrap();
+    let mut bx = dense::Matrix::new(n, n);
+    blas::mult(0.0, &mut bx, 1.0, &b, &x, None).unwrap();
+    let mut xbx = dense::Matrix::new(n, n);
+    blas::mult(0.0, &mut xbx, 1.0, &x, &bx, Some(OpCodes::TRANSA)).unwrap();
+    assert!(xbx.iter().all(|(i, j, v)| (v - if i == j { 1.0 } else { 0.0 }).abs() < 1e-10));
+    let (du, _) = lapack::eigen_sym_generalized(&a, &b, GeneralizedProblem::AxBx, Some(OpCodes::UPPER)).unwrap();
+    assert!(d.iter().all(|(k, v)| (v - du.get(k)).abs() < 1e-10));
+}
+
+#[test]
+fn test_gsvd() {
+    use super::gsvd::GSVD;
+    use super::Error;
+
+    let n = 3;
+    // A = U*diag(alpha)*X^T, B = V*diag(beta)*X^T, nonzero columns of U and V orthonormal
+    let check = |g: &GSVD, m: &dense::Matrix, f: &dense::Matrix, d: &vec::Vector| {
+        let (rows, _) = m.size();
+        let mut fd = f.clone();
+        for k in 0..n {
+            blas::scale(&mut fd.column_mut(k), d.get(k)).unwrap();
+        }
+        let mut p = dense::Matrix::new(rows, n);
+        blas::mult(0.0, &mut p, 1.0, &fd, g.x(), Some(OpCodes::TRANSB)).unwrap();
+        assert!(p.iter().all(|(i, j, v)| (v - m.get(i, j)).abs() < 1e-10));
+        let mut ftf = dense::Matrix::new(n, n);
+        blas::mult(0.0, &mut ftf, 1.0, f, f, Some(OpCodes::TRANSA)).unwrap();
+        assert!(ftf.iter().all(|(i, j, v)| (v - if i == j && d.get(i) != 0.0 { 1.0 } else { 0.0 }).abs() < 1e-10));
+    };
+    let pairs = |g: &GSVD| {
+        assert!(g.alpha().iter().all(|(k, v)| (v * v + g.beta().get(k) * g.beta().get(k) - 1.0).abs() < 1e-12));
+        assert!(g.alpha().get(0) >= g.alpha().get(1) && g.alpha().get(1) >= g.alpha().get(2));
+    };
+
+    let a: dense::Matrix = dense::Matrix::uniform(5, n);
+    let b: dense::Matrix = dense::Matrix::uniform(4, n);
+    let g = GSVD::new(&a, &b).unwrap();
+    pairs(&g);
+    check(&g, &a, g.u(), g.alpha());
+    check(&g, &b, g.v(), g.beta());
+    assert_eq!(g.values().get(0), g.alpha().get(0) / g.beta().get(0));
+
+    // p < n, leading pair is (1, 0)
+    let short: dense::Matrix = dense::Matrix::uniform(2, n);
+    let g = GSVD::new(&a, &short).unwrap();
+    pairs(&g);
+    assert_eq!((g.alpha().get(0), g.beta().get(0)), (1.0, 0.0));
+    assert_eq!(g.values().get(0), f64::INFINITY);
+    check(&g, &a, g.u(), g.alpha());
+    check(&g, &short, g.v(), g.beta());
+
+    // m < n, trailing pair is (0, 1)
+    let g = GSVD::new(&short, &b).unwrap();
+    pairs(&g);
+    assert_eq!((g.alpha().get(2), g.beta().get(2)), (0.0, 1.0));
+    check(&g, &short, g.u(), g.alpha());
+    check(&g, &b, g.v(), g.beta());
+
+    let row: dense::Matrix = dense::Matrix::uniform(1, n);
+    assert_eq!(GSVD::new(&row, &row).unwrap_err(), Error::ESize as i32);
+    assert_eq!(GSVD::new(&a, &dense::Matrix::new(4, n + 1)).unwrap_err(), Error::ESize as i32);
+}
+
+#[test]
+fn test_stats() {
+    use super::stats;
+
+    let x: dense::Matrix = dense::Matrix::from_rows(&[
+        &[1.0, 2.0, 0.5],
+        &[2.0, 1.0, 1.5],
+        &[3.0, 5.0, 0.0],
+        &[4.0, 3.0, 2.5],
+        &[6.0, 4.0, 1.0]]).unwrap();
+    let mc = stats::mean_cols(&x);
+    assert!([3.2, 3.0, 1.1].iter().enumerate().all(|(k, v)| (mc.get(k as u32) - v).abs() < 1e-15));
+    assert!((stats::mean_rows(&x).get(0) - 3.5 / 3.0).abs() < 1e-15);
+
+    let c = stats::covariance(&x).unwrap();
+    // var of first column: sum (x - 3.2)^2 / 4
+    assert!((c.get(0, 0) - 14.8 / 4.0).abs() < 1e-12);
+    assert_eq!(c.get(0, 1), c.get(1, 0));
+    let r = stats::correlation(&x).unwrap();
+    assert!((r.get(0, 1) - c.get(0, 1) / (c.get(0, 0) * c.get(1, 1)).sqrt()).abs() < 1e-12);
+    assert_eq!(r.get(2, 2), 1.0);
+
+    let p = stats::pca(&x, 2).unwrap();
+    assert_eq!(p.components.size(), (3, 2));
+    assert_eq!(p.scores.size(), (5, 2));
+    let trace = c.get(0, 0) + c.get(1, 1) + c.get(2, 2);
+    assert!((p.explained_variance.get(0) / trace - p.explained_variance_ratio.get(0)).abs() < 1e-12);
+    assert!(p.explained_variance.get(0) >= p.explained_variance.get(1));
+    // scores are projections of centered data
+    let mut xc = x.clone();
+    stats::center(&mut xc);
+    let mut proj = dense::Matrix::new(5, 2);
+    blas::mult(0.0, &mut proj, 1.0, &xc, &p.components, None).unwrap();
+    assert!(proj.iter().all(|(i, j, v)| (v - p.scores.get(i, j)).abs() < 1e-12));
+
+    // whitened data has identity covariance, distance equals norm of whitened row
+    let w = stats::whiten(&x).unwrap();
+    let cw = stats::covariance(&w).unwrap();
+    assert!(cw.iter().all(|(i, j, v)| (v - if i == j { 1.0 } else { 0.0 }).abs() < 1e-10));
+    let d = stats::mahalanobis(&x, &mc, &c).unwrap();
+    assert!(d.iter().all(|(i, v)| (v - blas::norm2(&w.row(i)).unwrap()).abs() < 1e-10));
+}
+
+#[test]
+fn test_multivariate_normal() {
+    use super::stats::{self, MultivariateNormal};
+
+    let mean = vec::Vector::new_from(vec![1.0, -2.0]);
+    let cov: dense::Matrix = dense::Matrix::from_rows(&[&[2.0, 0.6], &[0.6, 1.0]]).unwrap();
+    let mvn = MultivariateNormal::new(mean, cov).unwrap();
+    assert_eq!(mvn.dim(), 2);
+
+    // density of 2-d normal at x
+    let x = vec::Vector::new_from(vec![0.5, -1.0]);
+    let det: f64 = 2.0 * 1.0 - 0.6 * 0.6;
+    let (d0, d1): (f64, f64) = (-0.5, 1.0);
+    let quad = (1.0 * d0 * d0 - 2.0 * 0.6 * d0 * d1 + 2.0 * d1 * d1) / det;
+    let expect = (-0.5 * quad).exp() / (2.0 * std::f64::consts::PI * det.sqrt());
+    assert!((mvn.pdf(&x).unwrap() - expect).abs() < 1e-12);
+    assert!(mvn.log_pdf(&vec::Vector::new(3)).is_err());
+
+    assert_eq!(mvn.sample().size(), 2);
+    let s = mvn.sample_n(20000);
+    assert_eq!(s.size(), (20000, 2));
+    let m = stats::mean_cols(&s);
+    assert!((m.get(0) - 1.0).abs() < 0.1 && (m.get(1) + 2.0).abs() < 0.1);
+    let c = stats::covariance(&s).unwrap();
+    assert!((c.get(0, 0) - 2.0).abs() < 0.15 && (c.get(1, 0) - 0.6).abs() < 0.15);
+
+    let indefinite: dense::Matrix = dense::Matrix::from_rows(&[&[1.0, 2.0], &[2.0, 1.0]]).unwrap();
+    assert!(MultivariateNormal::new(vec::Vector::new(2), indefinite).is_err());
+}
+
+#[cfg(feature = "random")]
+#[test]
+fn test_random() {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use super::lapack;
+    use super::random::{self, Distribution};
+
+    // same seed, same matrix
+    let a: dense::Matrix = dense::Matrix::random_with(4, 3, &mut StdRng::seed_from_u64(7), Distribution::NORMAL);
+    let b: dense::Matrix = dense::Matrix::random_with(4, 3, &mut StdRng::seed_from_u64(7), Distribution::NORMAL);
+    assert!(a.iter().all(|(i, j, v)| v == b.get(i, j)));
+    let mut rng = StdRng::seed_from_u64(42);
+    let u: vec::Vector = vec::Vector::random_with(100, &mut rng, Distribution::Uniform { low: -1.0, high: 1.0 });
+    assert!(u.iter().all(|(_, v)| (-1.0..1.0).contains(&v)));
+
+    let q: dense::Matrix = random::orthogonal(5, &mut rng).unwrap();
+    let mut qtq = dense::Matrix::new(5, 5);
+    blas::mult(0.0, &mut qtq, 1.0, &q, &q, Some(OpCodes::TRANSA)).unwrap();
+    assert!(qtq.iter().all(|(i, j, v)| (v - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12));
+
+    let mut s = random::spd(5, 100.0, &mut rng).unwrap();
+    let mut d = vec::Vector::new(5);
+    lapack::eigen_sym(&mut d, &mut s, Some(OpCodes::LOWER)).unwrap();
+    let (lo, hi) = d.iter().fold((f64::MAX, 0.0f64), |(lo, hi), (_, v)| (lo.min(v), hi.max(v)));
+    assert!((lo - 0.01).abs() < 1e-12 && (hi - 1.0).abs() < 1e-12);
+    assert!(random::spd::<f64, _>(3, 0.5, &mut rng).is_err());
+
+    let sv: vec::Vector<f64> = vec::Vector::new_from(vec![3.0, 2.0, 0.5]);
+    let mut w = random::with_singular_values(6, 3, &sv, &mut rng).unwrap();
+    let mut sw = vec::Vector::new(3);
+    let (mut uu, mut vt) = (dense::Matrix::new(6, 3), dense::Matrix::new(3, 3));
+    lapack::svd(&mut sw, &mut uu, &mut vt, &mut w, None).unwrap();
+    assert!(sw.iter().all(|(k, v)| (v - sv.get(k)).abs() < 1e-12));
+}
+
+#[test]
+fn test_gallery() {
+    use super::{gallery, lapack};
+
+    let h: dense::Matrix = gallery::hilbert(4);
+    assert_eq!(h.get(1, 2), 0.25);
+    let l: dense::Matrix = gallery::lehmer(3);
+    assert_eq!((l.get(0, 2), l.get(2, 1)), (1.0 / 3.0, 2.0 / 3.0));
+    let p: dense::Matrix = gallery::pascal(5);
+    assert_eq!((p.get(4, 4), p.get(2, 3)), (70.0, 10.0));
+    assert!((lapack::det(&p).unwrap() - 1.0).abs() < 1e-8);
+    let f: dense::Matrix = gallery::frank(4);
+    assert_eq!((f.get(0, 0), f.get(1, 0), f.get(2, 0), f.get(1, 3)), (4.0, 3.0, 0.0, 1.0));
+    let w: dense::Matrix = gallery::wilkinson(7);
+    assert_eq!((w.get(0, 0), w.get(3, 3), w.get(3, 4), w.get(4, 3)), (3.0, 0.0, 1.0, 1.0));
+    let k: dense::Matrix = gallery::kahan(3, 1.2);
+    let (s, c) = 1.2f64.sin_cos();
+    assert!((k.get(1, 2) + c * s).abs() < 1e-15 && (k.get(2, 2) - s * s).abs() < 1e-15 && k.get(2, 1) == 0.0);
+
+    let x = vec::Vector::new_from(vec![1.0, 2.0, 3.0]);
+    let r = vec::Vector::new_from(vec![9.0, 4.0, 5.0, 6.0]);
+    let v = gallery::vandermonde(&x, 4);
+    assert_eq!((v.get(2, 0), v.get(2, 3)), (1.0, 27.0));
+    let t = gallery::toeplitz(&x, &r);
+    assert_eq!((t.size(), t.get(0, 0), t.get(2, 1), t.get(1, 3)), ((3, 4), 1.0, 2.0, 5.0));
+    let hk = gallery::hankel(&x, &r);
+    assert_eq!((hk.get(0, 0), hk.get(1, 1), hk.get(2, 1), hk.get(2, 3)), (1.0, 3.0, 4.0, 6.0));
+    let cc = gallery::circulant(&x);
+    assert_eq!((cc.get(0, 1), cc.get(1, 1), cc.get(0, 2)), (3.0, 1.0, 2.0));
+
+    // Laplacian eigenvalues 2 - 2*cos(k*pi/(n+1))
+    let n = 6;
+    let mut a: dense::Matrix = gallery::laplacian_1d(n);
+    let mut d = vec::Vector::new(n);
+    lapack::eigen_sym(&mut d, &mut a, Some(OpCodes::LOWER)).unwrap();
+    let mut e: Vec<f64> = d.iter().map(|(_, v)| v).collect();
+    e.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let pi = std::f64::consts::PI;
+    assert!(e.iter().enumerate().all(|(k, v)| (v - (2.0 - 2.0 * ((k + 1) as f64 * pi / 7.0).cos())).abs() < 1e-12));
+    let a2: dense::Matrix = gallery::laplacian_2d(3, 2);
+    assert_eq!((a2.size(), a2.get(0, 3), a2.get(2, 3), a2.get(1, 2)), ((6, 6), -1.0, 0.0, -1.0));
+
+    let ev = vec::Vector::new_from(vec![5.0, -1.0, 0.5, 2.0]);
+    let mut s = gallery::with_eigenvalues(&ev);
+    assert_eq!(s.get(1, 3), s.get(3, 1));
+    let mut d = vec::Vector::new(4);
+    lapack::eigen_sym(&mut d, &mut s, Some(OpCodes::LOWER)).unwrap();
+    let mut e: Vec<f64> = d.iter().map(|(_, v)| v).collect();
+    e.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!(e.iter().zip([-1.0, 0.5, 2.0, 5.0].iter()).all(|(u, v)| (u - v).abs() < 1e-12));
+}
+
+#[test]
+fn test_print_options() {
+    use super::print_options::{self, PrintOptions};
+
+    let a: dense::Matrix = dense::Matrix::from_rows(&[&[1.0, -22.5], &[3.25, 4.0]]).unwrap();
+    assert_eq!(format!("{:.2}", a), "[1.00  -22.50]\n[3.25    4.00]");
+    let d = format!("{:?}", a.column(1));
+    assert_eq!(d, "Vector { len: 2, stride: 1, owned: false }");
+    assert_eq!(format!("{:?}", a), "Matrix { shape: (2, 2), stride: 2, owned: true }");
+    assert_eq!(format!("{:?}", a.row(0)), "Vector { len: 2, stride: 2, owned: false }");
+    // zero size has no allocated storage and is reported as view
+    assert_eq!(format!("{:?}", dense::Matrix::<f64>::new(0, 3)), "Matrix { shape: (0, 3), stride: 0, owned: false }");
+
+    let opts = PrintOptions { precision: 1, threshold: 4, edgeitems: 1 };
+    let b: dense::Matrix = dense::Matrix::ones(6, 2);
+    let s = print_options::with(opts, || format!("{}", b));
+    assert_eq!(s, "[1.0  1.0]\n[...  ...]\n[1.0  1.0]");
+    let x = vec::Vector::new_from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(print_options::with(opts, || x.to_string()), "[1.0  ...  5.0]");
+    // local options are restored
+    assert_eq!(print_options::get(), PrintOptions::default());
+    assert_eq!(format!("{}", dense::Matrix::<f64>::new(0, 3)), "[]");
+}
@@ -1,69 +1,125 @@
-use libarmasd_sys as ffi;
-
 use std::mem;
-// use std::fmt;
+use std::fmt;
+use std::marker::PhantomData;
 use std::convert::TryInto;
 use serde::{Serialize, Serializer, Deserialize};
 use serde::ser::{SerializeStruct, SerializeSeq};
+use super::scalar::{Scalar, Dense};
+use super::print_options;
+
+#[derive(Deserialize)]
+struct VectorShadow<T> {
+    vec: Vec<T>
+}
 
 #[derive(Deserialize)]
-struct VectorShadow {
-    vec: Vec<f64>
+#[serde(from = "VectorShadow<T>", bound(deserialize = ""))]
+pub struct Vector<T: Scalar = f64> {
+    pub data: Dense<T>,
+    pub vec: Box<Vec<T>>
+}
+
+/// Immutable vector view borrowed from a matrix or matrix view.
+pub struct VectorView<'a, T: Scalar = f64> {
+    vec: Vector<T>,
+    _marker: PhantomData<&'a [T]>
+}
+
+/// Mutable vector view borrowed from a matrix or matrix view.
+pub struct VectorViewMut<'a, T: Scalar = f64> {
+    vec: Vector<T>,
+    _marker: PhantomData<&'a mut [T]>
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Vector operand of BLAS and LAPACK functions, implemented by `Vector`, `VectorView`
+/// and `VectorViewMut`.
+pub trait AsVector<T: Scalar>: sealed::Sealed {
+    /// Pointer to vector descriptor.
+    fn as_ptr(&self) -> *const Dense<T>;
+
+    /// Number of elements.
+    fn size(&self) -> u32;
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(from = "VectorShadow")]
-pub struct Vector {
-    pub data: ffi::armas_dense,
-    pub vec: Box<Vec<f64>>
+/// Writable vector operand, implemented by `Vector` and `VectorViewMut`.
+pub trait AsVectorMut<T: Scalar>: AsVector<T> {
+    /// Mutable pointer to vector descriptor.
+    fn as_mut_ptr(&mut self) -> *mut Dense<T>;
 }
 
-pub struct VectorIterator<'a> {
-    source: &'a Vector,
+pub struct VectorIterator<'a, T: Scalar = f64> {
+    source: &'a Vector<T>,
     index: u32,
     size: u32,
 }
 
-impl Vector {
-    pub fn as_ptr(&self) -> *const ffi::armas_dense {
+impl<T: Scalar> Vector<T> {
+    pub fn as_ptr(&self) -> *const Dense<T> {
         &self.data
     }
 
-    pub fn as_mut_ptr(&mut self) -> *mut ffi::armas_dense {
+    pub fn as_mut_ptr(&mut self) -> *mut Dense<T> {
         &mut self.data
     }
 
-    pub fn new(n: u32) -> Vector {
-        unsafe {
-            let mut x: Vec<f64> = Vec::with_capacity(n as usize);
-            x.set_len(n as usize);
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
-            ffi::armas_make(m.as_mut_ptr(), n as i32, 1, n as i32, x.as_mut_ptr());
-            Vector { data: m.assume_init(), vec: Box::new(x) }
-        }
+    /// Create new zero initialized vector of length n.
+    pub fn new(n: u32) -> Vector<T> {
+        Vector::filled(n, T::zero())
+    }
+
+    /// Create new zero initialized vector of length n.
+    pub fn zeros(n: u32) -> Vector<T> {
+        Vector::new(n)
+    }
+
+    /// Create new vector with all elements set to one.
+    pub fn ones(n: u32) -> Vector<T> {
+        Vector::filled(n, T::one())
+    }
+
+    /// Create new vector with all elements set to value.
+    pub fn filled(n: u32, value: T) -> Vector<T> {
+        Vector::new_from(vec![value; n as usize])
     }
 
-    pub fn new_from(mut v: Vec<f64>) -> Self {
+    /// Create new vector with uninitialized elements.
+    ///
+    /// # Safety
+    ///
+    /// Caller must write all elements before reading any of them.
+    pub unsafe fn uninit(n: u32) -> Vector<T> {
+        // Elements are written to the spare capacity through the descriptor, see Matrix::uninit.
+        let mut x: Vec<T> = Vec::with_capacity(n as usize);
+        let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
+        T::armas_make(m.as_mut_ptr(), n as i32, 1, n as i32, x.as_mut_ptr());
+        Vector { data: m.assume_init(), vec: Box::new(x) }
+    }
+
+    pub fn new_from(mut v: Vec<T>) -> Self {
         unsafe {
-            let mut m = mem::MaybeUninit::<ffi::armas_dense>::zeroed();
+            let mut m = mem::MaybeUninit::<Dense<T>>::zeroed();
             let n: u32 = v.len() as u32;
-            ffi::armas_make(m.as_mut_ptr(), n as i32, 1, n as i32, v.as_mut_ptr());
+            T::armas_make(m.as_mut_ptr(), n as i32, 1, n as i32, v.as_mut_ptr());
             Vector { data: m.assume_init(), vec: Box::new(v) }
         }
     }
 
-    pub fn uniform(n: u32) -> Vector {
+    pub fn uniform(n: u32) -> Vector<T> {
         let mut v = Vector::new(n);
         unsafe {
-            ffi::armas_set_all(v.as_mut_ptr(), ffi::armas_uniform, 0);
+            T::armas_set_all(v.as_mut_ptr(), T::UNIFORM, 0);
         }
         v
     }
 
-    pub fn normal(n: u32) -> Vector {
+    pub fn normal(n: u32) -> Vector<T> {
         let mut v = Vector::new(n);
         unsafe {
-            ffi::armas_set_all(v.as_mut_ptr(), ffi::armas_normal, 0);
+            T::armas_set_all(v.as_mut_ptr(), T::NORMAL, 0);
         }
         v
     }
@@ -75,45 +131,74 @@ impl Vector {
         return rows*cols;
     }
 
-    pub fn get(&self, index: u32) -> f64 {
+    pub fn get(&self, index: u32) -> T {
         unsafe {
-            ffi::armas_get_at_unsafe(&self.data, index.try_into().unwrap_or(0))
+            T::armas_get_at_unsafe(&self.data, index.try_into().unwrap_or(0))
         }
     }
 
-    pub fn set(&mut self, index: u32, value: f64) {
+    pub fn set(&mut self, index: u32, value: T) {
         unsafe {
-            ffi::armas_set_at_unsafe(&mut self.data, index.try_into().unwrap_or(0), value)
+            T::armas_set_at_unsafe(&mut self.data, index.try_into().unwrap_or(0), value)
         }
     }
 
     /// Copy self to destination.
     /// TODO: return self or dest? With error as Result<T, E>?
-    pub fn copy_to(&self, dst: &mut Vector) -> &Vector {
+    pub fn copy_to<V: AsVectorMut<T>>(&self, dst: &mut V) -> &Vector<T> {
         if self.size() != dst.size() {
             return &self;
         }
         unsafe {
-            ffi::armas_mcopy(&mut dst.data, &self.data, 0);
+            T::armas_mcopy(dst.as_mut_ptr(), &self.data, 0);
         }
         self
     }
 
-    pub fn iter(&self) -> VectorIterator {
+    // Copy elements of vector operand to new vector.
+    pub(crate) fn copy_from(x: &impl AsVector<T>) -> Vector<T> {
+        let mut v = Vector::new(x.size());
+        unsafe {
+            T::armas_mcopy(&mut v.data, x.as_ptr(), 0);
+        }
+        v
+    }
+
+    pub fn iter(&self) -> VectorIterator<T> {
         VectorIterator { source: self, index: 0, size: self.size() }
     }
 
 }
 
-impl Drop for Vector {
+impl<T: Scalar> Drop for Vector<T> {
     fn drop(&mut self) {
         unsafe {
-            ffi::armas_release(&mut self.data);
+            T::armas_release(&mut self.data);
         }
     }
 }
 
-impl Clone for Vector {
+/// Show length, element increment and whether vector owns its storage or is a view.
+/// Ownership is derived from allocated storage, zero length vectors are reported as views.
+impl<T: Scalar> fmt::Debug for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let stride = if self.data.rows == 1 { self.data.step } else { 1 };
+        f.debug_struct("Vector")
+            .field("len", &self.size())
+            .field("stride", &stride)
+            .field("owned", &(self.vec.capacity() > 0))
+            .finish()
+    }
+}
+
+/// Show elements on one row as set by `print_options`.
+impl<T: Scalar> fmt::Display for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        print_options::write_table(f, 1, self.size(), |_, k| self.get(k))
+    }
+}
+
+impl<T: Scalar> Clone for Vector<T> {
     fn clone(&self) -> Self {
         let mut vec = Vector::new(self.size());
         self.copy_to(&mut vec);
@@ -121,8 +206,132 @@ impl Clone for Vector {
     }
 }
 
-impl<'a> Iterator for VectorIterator<'a> {
-    type Item = (u32, f64);
+// Read-only accessors of vector views.
+macro_rules! view_accessors {
+    () => {
+        pub fn size(&self) -> u32 {
+            self.vec.size()
+        }
+
+        pub fn get(&self, index: u32) -> T {
+            self.vec.get(index)
+        }
+
+        pub fn iter(&self) -> VectorIterator<'_, T> {
+            self.vec.iter()
+        }
+
+        pub fn copy_to<V: AsVectorMut<T>>(&self, dst: &mut V) -> &Self {
+            self.vec.copy_to(dst);
+            self
+        }
+
+        /// Copy elements to new vector.
+        pub fn to_vector(&self) -> Vector<T> {
+            self.vec.clone()
+        }
+    }
+}
+
+impl<'a, T: Scalar> VectorView<'a, T> {
+    // Wrap vector view borrowed from a matrix.
+    pub(crate) fn new(vec: Vector<T>) -> VectorView<'a, T> {
+        VectorView { vec, _marker: PhantomData }
+    }
+
+    view_accessors!();
+}
+
+impl<'a, T: Scalar> VectorViewMut<'a, T> {
+    // Wrap vector view borrowed mutably from a matrix.
+    pub(crate) fn new(vec: Vector<T>) -> VectorViewMut<'a, T> {
+        VectorViewMut { vec, _marker: PhantomData }
+    }
+
+    view_accessors!();
+
+    pub fn set(&mut self, index: u32, value: T) {
+        self.vec.set(index, value)
+    }
+
+    // Borrow viewed elements as vector for crate routines taking &mut Vector.
+    pub(crate) fn as_vector_mut(&mut self) -> &mut Vector<T> {
+        &mut self.vec
+    }
+}
+
+impl<T: Scalar> sealed::Sealed for Vector<T> {}
+impl<'a, T: Scalar> sealed::Sealed for VectorView<'a, T> {}
+impl<'a, T: Scalar> sealed::Sealed for VectorViewMut<'a, T> {}
+
+impl<T: Scalar> AsVector<T> for Vector<T> {
+    fn as_ptr(&self) -> *const Dense<T> {
+        &self.data
+    }
+
+    fn size(&self) -> u32 {
+        Vector::size(self)
+    }
+}
+
+impl<T: Scalar> AsVectorMut<T> for Vector<T> {
+    fn as_mut_ptr(&mut self) -> *mut Dense<T> {
+        &mut self.data
+    }
+}
+
+impl<'a, T: Scalar> AsVector<T> for VectorView<'a, T> {
+    fn as_ptr(&self) -> *const Dense<T> {
+        &self.vec.data
+    }
+
+    fn size(&self) -> u32 {
+        self.vec.size()
+    }
+}
+
+impl<'a, T: Scalar> AsVector<T> for VectorViewMut<'a, T> {
+    fn as_ptr(&self) -> *const Dense<T> {
+        &self.vec.data
+    }
+
+    fn size(&self) -> u32 {
+        self.vec.size()
+    }
+}
+
+impl<'a, T: Scalar> AsVectorMut<T> for VectorViewMut<'a, T> {
+    fn as_mut_ptr(&mut self) -> *mut Dense<T> {
+        &mut self.vec.data
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for VectorView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.vec, f)
+    }
+}
+
+impl<'a, T: Scalar> fmt::Display for VectorView<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.vec, f)
+    }
+}
+
+impl<'a, T: Scalar> fmt::Debug for VectorViewMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.vec, f)
+    }
+}
+
+impl<'a, T: Scalar> fmt::Display for VectorViewMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.vec, f)
+    }
+}
+
+impl<'a, T: Scalar> Iterator for VectorIterator<'a, T> {
+    type Item = (u32, T);
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index >= self.size {
@@ -135,11 +344,11 @@ impl<'a> Iterator for VectorIterator<'a> {
     }
 }
 
-impl<'a> IntoIterator for &'a Vector {
-    type Item = (u32, f64);
-    type IntoIter = VectorIterator<'a>;
+impl<'a, T: Scalar> IntoIterator for &'a Vector<T> {
+    type Item = (u32, T);
+    type IntoIter = VectorIterator<'a, T>;
 
-    fn into_iter(self) -> VectorIterator<'a> {
+    fn into_iter(self) -> VectorIterator<'a, T> {
         self.iter()
     }
 }
@@ -147,7 +356,7 @@ impl<'a> IntoIterator for &'a Vector {
 /// Serialize vector elements thought VectorIterator as the source
 /// vector may be vector view of underlying matrix. Therefore the
 /// vector elements are not necessary in sequential memory addresses.
-impl<'a> Serialize for VectorIterator<'a> {
+impl<'a, T: Scalar> Serialize for VectorIterator<'a, T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -161,7 +370,7 @@ impl<'a> Serialize for VectorIterator<'a> {
     }
 }
 
-impl Serialize for Vector {
+impl<T: Scalar> Serialize for Vector<T> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -173,8 +382,8 @@ impl Serialize for Vector {
     }
 }
 
-impl From<VectorShadow> for Vector {
-    fn from(v: VectorShadow) -> Self {
+impl<T: Scalar> From<VectorShadow<T>> for Vector<T> {
+    fn from(v: VectorShadow<T>) -> Self {
         Vector::new_from(v.vec)
     }
 }
@@ -0,0 +1,16 @@
+//! Configuration flags are global, the test runs in its own test binary so that no
+//! other test computes while flags are changed.
+
+use armasd::{conf, ConfFlags};
+
+#[test]
+fn test_conf_flags() {
+    let saved = conf::optflags();
+    unsafe {
+        conf::set_extended_precision(true);
+        assert!(conf::optflags().contains(ConfFlags::EXTPREC));
+        conf::set_extended_precision(false);
+        assert!(!conf::optflags().contains(ConfFlags::EXTPREC));
+        conf::set_optflags(saved);
+    }
+}